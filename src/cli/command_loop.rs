@@ -4,6 +4,12 @@ use crate::page::Page;
 use crate::CedError;
 use crate::{cli::help, utils, CedResult, Command, Processor};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Environment variable to set polling interval of watch in seconds
+const WATCH_INTERVAL_ENV: &str = "CED_WATCH_INTERVAL";
 
 pub fn start_main_loop() -> CedResult<()> {
     let args: Vec<String> = std::env::args().collect();
@@ -14,6 +20,8 @@ pub fn start_main_loop() -> CedResult<()> {
     // Set temporary variables
     let mut command_exit = false;
     let mut write_confirm = false;
+    let mut schema_strict = false;
+    let mut watch = false;
     let mut import = None;
     let mut execute = None;
     let mut schema = None;
@@ -24,6 +32,8 @@ pub fn start_main_loop() -> CedResult<()> {
             FlagType::Version => help::print_version(),
             FlagType::Help => help::print_binary_help_text(),
             FlagType::Confirm => write_confirm = true,
+            FlagType::SchemaStrict => schema_strict = true,
+            FlagType::Watch => watch = true,
             FlagType::Argument => {
                 if !item.option.is_empty() {
                     // If given file is .ced format execute it
@@ -56,7 +66,24 @@ pub fn start_main_loop() -> CedResult<()> {
             FlagType::NoLog => {
                 command_loop.no_log();
             }
-            FlagType::None => (),
+            FlagType::NoConfirm => {
+                command_loop.no_confirm();
+            }
+            FlagType::OutputFormat => match item.option.parse() {
+                Ok(format) => command_loop.processor.set_output_format(format),
+                Err(_) => utils::write_to_stderr(&format!(
+                    "WRN : \"{}\" is not a valid output format\n",
+                    item.option
+                ))?,
+            },
+            FlagType::Delimiter => match utils::parse_delimiter(&item.option) {
+                Ok(delimiter) => command_loop.processor.set_delimiter(Some(delimiter)),
+                Err(_) => utils::write_to_stderr(&format!(
+                    "WRN : \"{}\" is not a valid delimiter. Comma is used instead\n",
+                    item.option
+                ))?,
+            },
+            FlagType::Unknown(_) => (),
         }
 
         if item.early_exit {
@@ -77,7 +104,7 @@ pub fn start_main_loop() -> CedResult<()> {
         feed_import(import, &mut command_loop)?;
     }
     if let Some(sch) = schema.as_ref() {
-        feed_schema(sch, &mut command_loop)?;
+        feed_schema(sch, schema_strict, &mut command_loop)?;
     }
     if let Some(cmd) = command.as_ref() {
         feed_command(cmd, &mut command_loop, write_confirm)?;
@@ -86,6 +113,13 @@ pub fn start_main_loop() -> CedResult<()> {
     if command_exit {
         return Ok(());
     }
+
+    if watch {
+        match import.as_ref() {
+            Some(file) => command_loop.watch(file),
+            None => utils::write_to_stderr("WRN : Watch requires a file to import\n")?,
+        }
+    }
     // Handle error inside loop
     if let Some(err) = command_loop.start_loop().err() {
         println!("{}", err);
@@ -117,11 +151,18 @@ fn feed_import(file: &str, command_loop: &mut CommandLoop) -> CedResult<()> {
     Ok(())
 }
 
-fn feed_schema(file: &str, command_loop: &mut CommandLoop) -> CedResult<()> {
-    if let Err(err) =
-        command_loop.feed_command(&Command::from_str(&format!("schema {} true", file))?, true)
-    {
+/// Apply schema to imported data
+///
+/// Strict schema doesn't overwrite unqualified values but exits with an error status.
+fn feed_schema(file: &str, strict: bool, command_loop: &mut CommandLoop) -> CedResult<()> {
+    if let Err(err) = command_loop.feed_command(
+        &Command::from_str(&format!("schema {} {}", file, !strict))?,
+        true,
+    ) {
         eprintln!("{}", err);
+        if strict {
+            std::process::exit(1);
+        }
         return Ok(());
     }
     Ok(())
@@ -134,35 +175,69 @@ fn feed_command(
 ) -> CedResult<()> {
     let command_split: Vec<&str> = command.split_terminator(';').collect();
     for command in command_split {
-        let command = Command::from_str(command)?;
+        let command = match Command::from_str(command) {
+            Ok(command) => command,
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        };
         // Write should confirm
-        if command.command_type == CommandType::Write && write_confirm {
+        if command.command_type == CommandType::Write
+            && write_confirm
+            && !command_loop.processor.no_confirm
+        {
             command_loop.feed_command(&Command::from_str("print")?, true)?;
-            utils::write_to_stdout("Overwrite ? (y/N) : ")?;
-            if utils::read_stdin(true)?.to_lowercase().as_str() != "y" {
+            if !command_loop.processor.confirm("Overwrite ?")? {
                 return Ok(());
             }
         }
 
+        // Failed command exits with a non-zero code so that scripts can detect it
         if let Err(err) = command_loop.feed_command(&command, true) {
             eprintln!("{}", err);
-            return Ok(());
+            std::process::exit(1);
         }
     }
 
+    // Differences or violations are not errors but also exit with a non-zero code
+    if command_loop.processor.check_failed {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
 pub struct CommandLoop {
     history: CommandHistory,
     processor: Processor,
+    /// Watched page and a flag set by a watcher thread when its file is modified
+    watched: Option<(String, Arc<AtomicBool>)>,
+}
+
+impl Default for CommandLoop {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CommandLoop {
     pub fn new() -> Self {
+        Self::with_processor(Processor::new())
+    }
+
+    /// Create a command loop with a configured processor
+    ///
+    /// History capacity of processor is used if it was set
+    pub fn with_processor(processor: Processor) -> Self {
+        let history = match processor.history_capacity {
+            Some(capacity) => CommandHistory::with_capacity(capacity),
+            None => CommandHistory::new(),
+        };
         Self {
-            history: CommandHistory::new(),
-            processor: Processor::new(),
+            history,
+            processor,
+            watched: None,
         }
     }
 
@@ -170,8 +245,70 @@ impl CommandLoop {
         self.processor.no_loop = tv;
     }
 
+    /// Set capacity of undo history
+    ///
+    /// This discards existing history, thus should be called before any command is run
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.processor.history_capacity = Some(capacity);
+        self.history = CommandHistory::with_capacity(capacity);
+    }
+
     pub fn no_log(&mut self) {
-        self.processor.print_logs = false;
+        self.processor.set_print_logs(false);
+    }
+
+    pub fn no_confirm(&mut self) {
+        self.processor.no_confirm = true;
+    }
+
+    /// Watch a file and reload its page when it is modified
+    ///
+    /// A background thread polls modification time of the file. Page is reloaded before next
+    /// command is executed. Polling interval is set by CED_WATCH_INTERVAL in seconds, default is 1.
+    pub fn watch(&mut self, file: &str) {
+        let interval = std::env::var(WATCH_INTERVAL_ENV)
+            .ok()
+            .and_then(|var| var.parse::<u64>().ok())
+            .unwrap_or(1);
+        let changed = Arc::new(AtomicBool::new(false));
+        // Page imported from a file is named after the file path
+        self.watched.replace((file.to_owned(), changed.clone()));
+
+        let file = file.to_owned();
+        let modified_time = move || -> Option<SystemTime> {
+            std::fs::metadata(&file)
+                .and_then(|meta| meta.modified())
+                .ok()
+        };
+        std::thread::spawn(move || {
+            let mut last_modified = modified_time();
+            loop {
+                std::thread::sleep(Duration::from_secs(interval));
+                let modified = modified_time();
+                if modified != last_modified {
+                    last_modified = modified;
+                    changed.store(true, Ordering::Relaxed);
+                }
+            }
+        });
+    }
+
+    /// Reload watched page if its file was modified
+    ///
+    /// History is cleared because snapshots refer to stale data
+    fn reload_if_changed(&mut self) -> CedResult<()> {
+        let page = match self.watched.as_ref() {
+            Some((page, changed)) if changed.swap(false, Ordering::Relaxed) => page.clone(),
+            _ => return Ok(()),
+        };
+        match self.processor.reload_from_file(&page) {
+            Ok(_) => {
+                self.history.clear();
+                utils::write_to_stdout(": File reloaded :\n")?;
+            }
+            Err(err) => utils::write_to_stderr(&format!("{}\n", err))?,
+        }
+        Ok(())
     }
 
     pub fn feed_command(&mut self, command: &Command, panic: bool) -> CedResult<()> {
@@ -185,13 +322,26 @@ impl CommandLoop {
         utils::write_to_stdout("Ced, a csv editor\n")?;
         let mut read_byte = 1;
         while read_byte != 0 && CommandType::Exit != command.command_type {
-            utils::write_to_stdout(">> ")?;
+            // Show current page only when there are multiple pages
+            match self.processor.get_cursor() {
+                Some(cursor) if self.processor.get_page_count() > 1 => {
+                    utils::write_to_stdout(&format!("[{}] >> ", cursor))?
+                }
+                _ => utils::write_to_stdout(">> ")?,
+            }
             let mut input = String::new();
             read_byte = utils::read_stdin_until_eof(true, &mut input)?;
+            self.reload_if_changed()?;
             if input.is_empty() {
                 continue;
             }
-            command = Command::from_str(&input)?;
+            command = match Command::from_str(&input) {
+                Ok(command) => command,
+                Err(err) => {
+                    utils::write_to_stderr(&format!("{}\n", err))?;
+                    continue;
+                }
+            };
             self.execute_command(&command, false)?;
         }
         Ok(())
@@ -203,9 +353,24 @@ impl CommandLoop {
         #[cfg(debug_assertions)]
         utils::write_to_stdout(&format!("{:?}\n", command))?;
 
+        let mut merge_snapshot = None;
         match command.command_type {
             CommandType::History => {
-                self.print_history()?;
+                let limit = match command.arguments.first() {
+                    Some(arg) => match arg.parse::<usize>() {
+                        Ok(limit) => Some(limit),
+                        Err(err) => {
+                            let err = CedError::from(err);
+                            if panic {
+                                return Err(err);
+                            }
+                            utils::write_to_stderr(&(err.to_string() + "\n"))?;
+                            return Ok(());
+                        }
+                    },
+                    None => None,
+                };
+                self.print_history(limit)?;
             }
             CommandType::Undo | CommandType::Redo => {
                 if command.command_type == CommandType::Undo {
@@ -234,7 +399,13 @@ impl CommandLoop {
             // Un-redoable commands
             CommandType::Exit
             | CommandType::Import
+            | CommandType::ImportStdin
+            | CommandType::ImportTsv
+            | CommandType::DropPages
             | CommandType::Export
+            | CommandType::ExportJson
+            | CommandType::ExportJsonl
+            | CommandType::ExportTsv
             | CommandType::Create
             | CommandType::Write
             | CommandType::None
@@ -244,39 +415,123 @@ impl CommandLoop {
             | CommandType::PrintCell
             | CommandType::PrintRow
             | CommandType::PrintColumn
-            | CommandType::Print => (),
+            | CommandType::Print
+            | CommandType::PrintSchema
+            | CommandType::Set
+            | CommandType::PrintCsv
+            | CommandType::MaskList
+            | CommandType::PrintColumnsOnly
+            | CommandType::PrintRows
+            | CommandType::Diff
+            | CommandType::Join
+            | CommandType::ExportGz
+            | CommandType::Count
+            | CommandType::Unique
+            | CommandType::Find
+            | CommandType::SchemaInfer
+            | CommandType::SchemaValidate
+            | CommandType::SnapshotList
+            | CommandType::SnapshotSave
+            | CommandType::Validate
+            | CommandType::PageDrop
+            | CommandType::PageCopy
+            | CommandType::PageRename
+            | CommandType::PageSwitch
+            | CommandType::PageList
+            | CommandType::Stats => (),
+
+            // Only keep variant modifies a page
+            CommandType::Filter if !command.arguments.iter().any(|arg| arg == "keep") => (),
+            CommandType::Sample if !is_sample_kept(&command.arguments) => (),
+            CommandType::DeleteRowsMatching if is_dry_run(&command.arguments) => (),
 
             // Meta related
             CommandType::Help | CommandType::Version => (),
 
-            _ => {
-                let cursor = self
-                    .processor
-                    .get_cursor()
-                    .ok_or_else(|| CedError::InvalidPageOperation("Page is empty".to_string()))?;
-                self.history
-                    .take_snapshot(self.processor.get_page_data(&cursor)?, command.command_type)
+            // Snapshot is taken from destination page but recorded only after a merge succeeds
+            CommandType::PageMerge => {
+                merge_snapshot = command
+                    .arguments
+                    .get(1)
+                    .and_then(|dest| self.processor.get_page_data(dest).ok().cloned());
             }
+
+            // A script is undone as a whole, thus snapshot is taken only once before execution
+            CommandType::Execute => self.take_snapshot(command.command_type)?,
+
+            _ => self.take_snapshot(command.command_type)?,
         }
 
-        if let Err(err) = self.processor.execute_command(command) {
+        let cursor = self.processor.get_cursor();
+        let result = self.processor.execute_command(command);
+        let succeeded = result.is_ok();
+        if let Err(err) = result {
             if panic {
                 return Err(err);
             } else {
                 utils::write_to_stderr(&(err.to_string() + "\n"))?;
             }
         }
+
+        // Merge moves cursor to destination page, whose history starts from the snapshot
+        if let (true, Some(snapshot)) = (succeeded, merge_snapshot) {
+            if self.processor.get_cursor() != cursor {
+                self.history.clear();
+            }
+            self.history.take_snapshot(&snapshot, command.command_type);
+        }
+
+        // History is only valid for a page where snapshots were taken
+        if matches!(
+            command.command_type,
+            CommandType::PageSwitch | CommandType::PageDrop | CommandType::Execute
+        ) && self.processor.get_cursor() != cursor
+        {
+            self.history.clear();
+        }
+
+        // Every command needs a page to work on
+        if self.processor.get_cursor().is_none() {
+            self.add_empty_page()?;
+        }
+        Ok(())
+    }
+
+    /// Take snapshot of current page
+    fn take_snapshot(&mut self, command_type: CommandType) -> CedResult<()> {
+        let cursor = self
+            .processor
+            .get_cursor()
+            .ok_or_else(|| CedError::InvalidPageOperation("Page is empty".to_string()))?;
+        self.history
+            .take_snapshot(self.processor.get_page_data(&cursor)?, command_type);
         Ok(())
     }
 
     /// Print history list
-    fn print_history(&self) -> CedResult<()> {
+    ///
+    /// Current undo position is marked with an arrow. Only recent entries are printed if limit
+    /// is given.
+    fn print_history(&self, limit: Option<usize>) -> CedResult<()> {
+        if self.history.is_empty() {
+            return utils::write_to_stdout(": History is empty :\n");
+        }
+        let len = self.history.memento_history.len();
+        let skip = limit.map(|limit| len.saturating_sub(limit)).unwrap_or(0);
         let print = self
             .history
             .memento_history
             .iter()
             .enumerate()
-            .map(|(idx, record)| format!("{} : {} \n", idx, record.command))
+            .skip(skip)
+            .map(|(idx, record)| {
+                let marker = if idx + 1 == self.history.index {
+                    "->"
+                } else {
+                    "  "
+                };
+                format!("{} [{}] {}\n", marker, idx, record.command)
+            })
             .collect::<String>();
         utils::write_to_stdout(&print)
     }
@@ -312,7 +567,19 @@ impl CommandLoop {
     }
 
     fn add_empty_page(&mut self) -> CedResult<()> {
-        self.processor.add_page("\\EMPTY", "", false, None, false)?;
+        self.processor
+            .add_page("\\EMPTY", "", false, None, false, None)?;
         Ok(())
     }
 }
+
+/// Check if arguments of sample request to keep sampled rows only
+fn is_sample_kept(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "keep" || arg == "--keep")
+}
+
+/// Check if arguments of delete-rows-matching request a dry run
+fn is_dry_run(args: &[String]) -> bool {
+    args.get(2).map(|arg| arg.as_str()) == Some("dry-run")
+        && args.get(3).map(|arg| arg.as_str()) != Some("false")
+}
@@ -26,33 +26,85 @@ pub fn print_command_help(command: CommandType) {
         CommandType::Write => include_str!("../help/raw/04_write"),
         CommandType::Import => include_str!("../help/raw/03_import"),
         CommandType::ImportRaw => include_str!("../help/raw/03_import_raw"),
+        CommandType::ImportTsv => include_str!("../help/raw/03_import_tsv"),
+        CommandType::Append => include_str!("../help/raw/03_append"),
+        CommandType::ImportStdin => include_str!("../help/raw/03_import_stdin"),
+        CommandType::DropPages => include_str!("../help/raw/03_drop_pages"),
+        CommandType::PageList => include_str!("../help/raw/03_page_list"),
+        CommandType::PageSwitch => include_str!("../help/raw/03_page_switch"),
+        CommandType::PageRename => include_str!("../help/raw/03_page_rename"),
+        CommandType::PageCopy => include_str!("../help/raw/03_page_copy"),
+        CommandType::PageDrop => include_str!("../help/raw/03_page_drop"),
+        CommandType::PageMerge => include_str!("../help/raw/03_page_merge"),
+        CommandType::Join => include_str!("../help/raw/03_join"),
+        CommandType::Diff => include_str!("../help/raw/03_diff"),
+        CommandType::SnapshotSave => include_str!("../help/raw/03_snapshot_save"),
+        CommandType::SnapshotRestore => include_str!("../help/raw/03_snapshot_restore"),
+        CommandType::SnapshotList => include_str!("../help/raw/03_snapshot_list"),
         CommandType::Export => include_str!("../help/raw/04_export"),
+        CommandType::ExportJson => include_str!("../help/raw/04_export_json"),
+        CommandType::ExportJsonl => include_str!("../help/raw/04_export_jsonl"),
+        CommandType::ExportTsv => include_str!("../help/raw/04_export_tsv"),
+        CommandType::ExportGz => include_str!("../help/raw/04_export_gz"),
         CommandType::AddRow => include_str!("../help/raw/08_add_row"),
         CommandType::AddColumn => include_str!("../help/raw/09_add_column"),
+        CommandType::CopyColumn => include_str!("../help/raw/09_copy_column"),
         CommandType::DeleteRow => include_str!("../help/raw/13_delete_row"),
+        CommandType::DeleteRowsMatching => include_str!("../help/raw/13_delete_rows_matching"),
+        CommandType::Clear => include_str!("../help/raw/13_clear"),
+        CommandType::Dedupe => include_str!("../help/raw/13_dedupe"),
         CommandType::DeleteColumn => include_str!("../help/raw/14_delete_column"),
         CommandType::EditCell => include_str!("../help/raw/10_edit"),
         CommandType::EditColumn => include_str!("../help/raw/12_edit_column"),
+        CommandType::ReplaceAll => include_str!("../help/raw/12_replace_all"),
+        CommandType::FindReplace => include_str!("../help/raw/12_find_replace"),
+        CommandType::EditColumnRegex => include_str!("../help/raw/12_edit_column_regex"),
+        CommandType::EditCellsMatching => include_str!("../help/raw/12_edit_cells_matching"),
+        CommandType::Normalize => include_str!("../help/raw/12_normalize"),
+        CommandType::FillDown => include_str!("../help/raw/12_fill_down"),
+        CommandType::FillUp => include_str!("../help/raw/12_fill_up"),
         CommandType::RenameColumn => include_str!("../help/raw/15_rename_column"),
         CommandType::EditRow => include_str!("../help/raw/11_edit_row"),
         CommandType::EditRowMultiple => include_str!("../help/raw/11_edit_row"),
         CommandType::MoveRow => include_str!("../help/raw/16_move"),
+        CommandType::CopyRow => include_str!("../help/raw/16_copy_row"),
+        CommandType::SortRow => include_str!("../help/raw/16_sort_row"),
+        CommandType::SwapRows => include_str!("../help/raw/16_swap_rows"),
         CommandType::MoveColumn => include_str!("../help/raw/17_move_column"),
+        CommandType::SwapColumns => include_str!("../help/raw/17_swap_columns"),
+        CommandType::Transpose => include_str!("../help/raw/17_transpose"),
         CommandType::Exit => include_str!("../help/raw/32_quit"),
         // TODO
         // Unimplemented!
         CommandType::Execute => include_str!("../help/raw/32_quit"),
+        CommandType::Set => include_str!("../help/raw/041_set"),
         CommandType::Print => include_str!("../help/raw/05_print"),
+        CommandType::PrintCsv => include_str!("../help/raw/05_print_csv"),
         CommandType::PrintCell => include_str!("../help/raw/05_print_cell"),
         CommandType::PrintRow => include_str!("../help/raw/05_print_row"),
+        CommandType::PrintRows => include_str!("../help/raw/05_print_rows"),
         CommandType::PrintColumn => include_str!("../help/raw/06_print_column"),
+        CommandType::PrintColumnsOnly => include_str!("../help/raw/06_print_columns_only"),
+        CommandType::MaskColumn => include_str!("../help/raw/06_mask_column"),
+        CommandType::UnmaskColumn => include_str!("../help/raw/06_unmask_column"),
+        CommandType::MaskList => include_str!("../help/raw/06_mask_list"),
+        CommandType::Filter => include_str!("../help/raw/06_filter"),
+        CommandType::Sample => include_str!("../help/raw/06_sample"),
+        CommandType::Find => include_str!("../help/raw/06_find"),
+        CommandType::Stats => include_str!("../help/raw/06_stats"),
+        CommandType::Unique => include_str!("../help/raw/06_unique"),
+        CommandType::Count => include_str!("../help/raw/06_count"),
+        CommandType::Validate => include_str!("../help/raw/06_validate"),
         CommandType::Limit => include_str!("../help/raw/18_limit"),
         // TODO
         // Unimplemented!
         CommandType::LimitPreset => include_str!("../help/raw/18_limit"),
         CommandType::Schema => include_str!("../help/raw/19_schema"),
         CommandType::SchemaInit => include_str!("../help/raw/21_schema_init"),
+        CommandType::SchemaInfer => include_str!("../help/raw/21_schema_infer"),
         CommandType::SchemaExport => include_str!("../help/raw/20_schema_export"),
+        CommandType::PrintSchema => include_str!("../help/raw/20_print_schema"),
+        CommandType::SchemaValidate => include_str!("../help/raw/21_schema_validate"),
         CommandType::History => include_str!("../help/raw/24_history"),
         CommandType::None => "No such command to print a help message.\n",
     };
@@ -1,4 +1,3 @@
 pub(crate) mod command_loop;
 pub(crate) mod help;
 pub(crate) mod parse;
-pub(crate) mod preset;
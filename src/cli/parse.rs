@@ -1,3 +1,21 @@
+use crate::utils;
+
+/// Long flags to suggest from when an unknown flag is given
+const LONG_FLAGS: [&str; 12] = [
+    "--version",
+    "--help",
+    "--command",
+    "--schema",
+    "--schema-strict",
+    "--confirm",
+    "--no-confirm",
+    "--nolog",
+    "--no-log",
+    "--output-format",
+    "--delimiter",
+    "--watch",
+];
+
 /// Argument parser
 pub struct Parser {
     flags: Vec<Flag>,
@@ -46,6 +64,24 @@ impl Parser {
 
             let flag = Self::match_word(word);
 
+            // Warn and continue
+            if let FlagType::Unknown(word) = &flag.ftype {
+                let suggestion = if word.starts_with("--") {
+                    utils::closest_match(word, &LONG_FLAGS)
+                } else {
+                    None
+                };
+                let warning = match suggestion {
+                    Some(suggestion) => format!(
+                        "WRN : Unknown flag '{}', did you mean '{}'?\n",
+                        word, suggestion
+                    ),
+                    None => format!("WRN : Unknown flag '{}'\n", word),
+                };
+                utils::write_to_stderr(&warning).expect("Failed to print to terminal");
+                return false;
+            }
+
             if flag.early_exit {
                 self.flags = vec![flag];
                 return true;
@@ -55,9 +91,7 @@ impl Parser {
                 self.accept_flag_option = true;
             }
 
-            if flag.ftype != FlagType::None {
-                self.flags.push(flag);
-            }
+            self.flags.push(flag);
         }
 
         false
@@ -69,9 +103,14 @@ impl Parser {
             "--help" | "-h" => Flag::help(),
             "--command" | "-c" => Flag::command(),
             "--schema" | "-s" => Flag::schema(),
+            "--schema-strict" | "-S" => Flag::schema_strict(),
             "--confirm" | "-C" => Flag::confirm(),
-            "--nolog" | "-n" => Flag::nolog(),
-            _ => Flag::empty(),
+            "--no-confirm" | "-y" => Flag::no_confirm(),
+            "--nolog" | "--no-log" | "-n" | "-L" => Flag::nolog(),
+            "--output-format" | "-o" => Flag::output_format(),
+            "--delimiter" | "-d" => Flag::delimiter(),
+            "--watch" | "-w" => Flag::watch(),
+            _ => Flag::unknown(word),
         }
     }
 }
@@ -85,9 +124,9 @@ pub struct Flag {
 }
 
 impl Flag {
-    pub fn empty() -> Self {
+    pub fn unknown(word: &str) -> Self {
         Self {
-            ftype: FlagType::None,
+            ftype: FlagType::Unknown(word.trim().to_string()),
             need_option: false,
             option: String::new(),
             early_exit: false,
@@ -112,6 +151,15 @@ impl Flag {
         }
     }
 
+    pub fn schema_strict() -> Self {
+        Self {
+            ftype: FlagType::SchemaStrict,
+            need_option: false,
+            option: String::new(),
+            early_exit: false,
+        }
+    }
+
     pub fn command() -> Self {
         Self {
             ftype: FlagType::Command,
@@ -130,6 +178,15 @@ impl Flag {
         }
     }
 
+    pub fn no_confirm() -> Self {
+        Self {
+            ftype: FlagType::NoConfirm,
+            need_option: false,
+            option: String::new(),
+            early_exit: false,
+        }
+    }
+
     pub fn nolog() -> Self {
         Self {
             ftype: FlagType::NoLog,
@@ -139,6 +196,33 @@ impl Flag {
         }
     }
 
+    pub fn output_format() -> Self {
+        Self {
+            ftype: FlagType::OutputFormat,
+            need_option: true,
+            option: String::new(),
+            early_exit: false,
+        }
+    }
+
+    pub fn delimiter() -> Self {
+        Self {
+            ftype: FlagType::Delimiter,
+            need_option: true,
+            option: String::new(),
+            early_exit: false,
+        }
+    }
+
+    pub fn watch() -> Self {
+        Self {
+            ftype: FlagType::Watch,
+            need_option: false,
+            option: String::new(),
+            early_exit: false,
+        }
+    }
+
     pub fn version() -> Self {
         Self {
             ftype: FlagType::Version,
@@ -165,7 +249,12 @@ pub enum FlagType {
     Confirm,
     Help,
     Schema,
+    SchemaStrict,
     Version,
+    NoConfirm,
     NoLog,
-    None,
+    OutputFormat,
+    Delimiter,
+    Watch,
+    Unknown(String),
 }
@@ -3,8 +3,9 @@ use crate::cli::help;
 use crate::error::{CedError, CedResult};
 #[cfg(feature = "cli")]
 use crate::page::Page;
-use crate::processor::Processor;
+use crate::processor::{OutputFormat, Processor, TextCase};
 use crate::utils::{self, subprocess};
+use crate::validation::ValidationError;
 use dcsv::{Column, Row, LIMITER_ATTRIBUTE_LEN, SCHEMA_HEADER};
 use dcsv::{Value, ValueLimiter, ValueType};
 use std::io::Write;
@@ -25,31 +26,82 @@ pub enum CommandType {
     Write,
     Import,
     ImportRaw,
+    ImportTsv,
+    Append,
+    ImportStdin,
+    DropPages,
+    PageList,
+    PageSwitch,
+    PageRename,
+    PageCopy,
+    PageDrop,
+    PageMerge,
+    Join,
+    Diff,
+    SnapshotSave,
+    SnapshotRestore,
+    SnapshotList,
     Export,
+    ExportJson,
+    ExportJsonl,
+    ExportTsv,
+    ExportGz,
     AddRow,
     AddColumn,
+    CopyColumn,
     DeleteRow,
+    DeleteRowsMatching,
+    Clear,
+    Dedupe,
     DeleteColumn,
     EditCell,
     EditColumn,
+    ReplaceAll,
+    FindReplace,
+    EditColumnRegex,
+    EditCellsMatching,
+    Normalize,
+    FillDown,
+    FillUp,
     RenameColumn,
     EditRow,
     #[cfg(feature = "cli")]
     EditRowMultiple,
     MoveRow,
+    CopyRow,
+    SortRow,
+    SwapRows,
     MoveColumn,
+    SwapColumns,
+    Transpose,
     Exit,
     Execute,
+    Set,
     Print,
+    PrintCsv,
     PrintCell,
     PrintRow,
+    PrintRows,
     PrintColumn,
+    PrintColumnsOnly,
+    MaskColumn,
+    UnmaskColumn,
+    MaskList,
+    Filter,
+    Sample,
+    Find,
+    Stats,
+    Unique,
+    Count,
+    Validate,
     Limit,
-    #[cfg(feature = "cli")]
     LimitPreset,
     Schema,
     SchemaInit,
+    SchemaInfer,
     SchemaExport,
+    PrintSchema,
+    SchemaValidate,
     History,
     None,
 }
@@ -60,8 +112,101 @@ impl std::fmt::Display for CommandType {
     }
 }
 
-// TODO
-// Currently this never fails
+/// Long names of commands to suggest from when an unknown command is given
+///
+/// Aliases are excluded on purpose. They are only a few characters long and would be the closest
+/// match for almost any short typo.
+pub(crate) const COMMAND_NAMES: &[&str] = &[
+    #[cfg(feature = "cli")]
+    "version",
+    #[cfg(feature = "cli")]
+    "help",
+    "import",
+    "import-raw",
+    "import-tsv",
+    "append",
+    "import-stdin",
+    "drop-pages",
+    "page-list",
+    "page-switch",
+    "page-rename",
+    "page-copy",
+    "page-drop",
+    "page-merge",
+    "join",
+    "diff",
+    "snapshot-save",
+    "snapshot-restore",
+    "snapshot-list",
+    "export",
+    "export-json",
+    "export-jsonl",
+    "export-tsv",
+    "export-gz",
+    "execute",
+    "set",
+    "create",
+    "write",
+    "print",
+    "print-csv",
+    "print-cell",
+    "print-row",
+    "print-rows",
+    "print-column",
+    "print-columns-only",
+    "mask-column",
+    "unmask-column",
+    "mask-list",
+    "filter",
+    "sample",
+    "find",
+    "stats",
+    "unique",
+    "count",
+    "validate",
+    "add-row",
+    "exit",
+    "add-column",
+    "copy-column",
+    "delete-row",
+    "delete-rows-matching",
+    "clear",
+    "dedupe",
+    "delete-column",
+    "edit",
+    "edit-row",
+    #[cfg(feature = "cli")]
+    "edit-row-multiple",
+    "edit-column",
+    "replace-all",
+    "find-replace",
+    "edit-column-regex",
+    "edit-cells-matching",
+    "normalize",
+    "fill-down",
+    "fill-up",
+    "rename-column",
+    "move-row",
+    "copy-row",
+    "sort-row",
+    "swap-rows",
+    "move-column",
+    "swap-columns",
+    "transpose",
+    "limit",
+    "limit-preset",
+    "undo",
+    "redo",
+    "schema",
+    "schema-init",
+    "schema-infer",
+    "schema-export",
+    "print-schema",
+    "schema-validate",
+    "history",
+];
+
+/// Empty string is parsed as None. Unknown command fails with a suggestion of a similar command.
 impl FromStr for CommandType {
     type Err = CedError;
     fn from_str(src: &str) -> Result<Self, Self::Err> {
@@ -72,45 +217,97 @@ impl FromStr for CommandType {
             "help" | "h" => Self::Help,
             "import" | "i" => Self::Import,
             "import-raw" | "ir" => Self::ImportRaw,
+            "import-tsv" | "it" => Self::ImportTsv,
+            "append" | "ap" => Self::Append,
+            "import-stdin" | "ist" => Self::ImportStdin,
+            "drop-pages" | "dp" => Self::DropPages,
+            "page-list" | "pgl" => Self::PageList,
+            "page-switch" | "pgs" => Self::PageSwitch,
+            "page-rename" | "pgr" => Self::PageRename,
+            "page-copy" | "pgcp" => Self::PageCopy,
+            "page-drop" | "pgd" => Self::PageDrop,
+            "page-merge" | "pgm" => Self::PageMerge,
+            "join" | "jn" => Self::Join,
+            "diff" | "di" => Self::Diff,
+            "snapshot-save" | "sns" => Self::SnapshotSave,
+            "snapshot-restore" | "snr" => Self::SnapshotRestore,
+            "snapshot-list" | "snl" => Self::SnapshotList,
             "export" | "x" => Self::Export,
+            "export-json" | "xj" => Self::ExportJson,
+            "export-jsonl" | "xjl" => Self::ExportJsonl,
+            "export-tsv" | "xt" => Self::ExportTsv,
+            "export-gz" | "xgz" => Self::ExportGz,
             "execute" | "ex" => Self::Execute,
+            "set" => Self::Set,
             "create" | "c" => Self::Create,
             "write" | "w" => Self::Write,
             "print" | "p" => Self::Print,
+            "print-csv" | "pcsv" => Self::PrintCsv,
             "print-cell" | "pc" => Self::PrintCell,
             "print-row" | "pr" => Self::PrintRow,
+            "print-rows" | "prrs" => Self::PrintRows,
             "print-column" | "pl" => Self::PrintColumn,
+            "print-columns-only" | "pco" => Self::PrintColumnsOnly,
+            "mask-column" | "msk" => Self::MaskColumn,
+            "unmask-column" | "umsk" => Self::UnmaskColumn,
+            "mask-list" | "mskl" => Self::MaskList,
+            "filter" | "fi" => Self::Filter,
+            "sample" | "smp" => Self::Sample,
+            "find" | "fnd" => Self::Find,
+            "stats" | "st" => Self::Stats,
+            "unique" | "uq" => Self::Unique,
+            "count" | "cnt" => Self::Count,
+            "validate" | "vl" => Self::Validate,
             "add-row" | "ar" => Self::AddRow,
             "exit" | "quit" | "q" => Self::Exit,
             "add-column" | "ac" => Self::AddColumn,
+            "copy-column" | "cprc" => Self::CopyColumn,
             "delete-row" | "dr" => Self::DeleteRow,
+            "delete-rows-matching" | "drm" => Self::DeleteRowsMatching,
+            "clear" | "clr" => Self::Clear,
+            "dedupe" | "dd" => Self::Dedupe,
             "delete-column" | "dc" => Self::DeleteColumn,
             "edit" | "edit-cell" | "e" => Self::EditCell,
             "edit-row" | "er" => Self::EditRow,
             #[cfg(feature = "cli")]
             "edit-row-multiple" | "erm" => Self::EditRowMultiple,
             "edit-column" | "ec" => Self::EditColumn,
+            "replace-all" | "ra" => Self::ReplaceAll,
+            "find-replace" | "fr" => Self::FindReplace,
+            "edit-column-regex" | "ecr" => Self::EditColumnRegex,
+            "edit-cells-matching" | "ecm" => Self::EditCellsMatching,
+            "normalize" | "nm" => Self::Normalize,
+            "fill-down" | "fld" => Self::FillDown,
+            "fill-up" | "flu" => Self::FillUp,
             "rename-column" | "rc" => Self::RenameColumn,
             "move-row" | "move" | "m" => Self::MoveRow,
+            "copy-row" | "cpry" => Self::CopyRow,
+            "sort-row" | "sr" => Self::SortRow,
+            "swap-rows" | "swr" => Self::SwapRows,
             "move-column" | "mc" => Self::MoveColumn,
+            "swap-columns" | "swc" => Self::SwapColumns,
+            "transpose" | "tp" => Self::Transpose,
             "limit" | "l" => Self::Limit,
-            #[cfg(feature = "cli")]
             "limit-preset" | "lp" => Self::LimitPreset,
             "undo" | "u" => Self::Undo,
             "redo" | "r" => Self::Redo,
             "schema" | "s" => Self::Schema,
             "schema-init" | "si" => Self::SchemaInit,
+            "schema-infer" | "sif" => Self::SchemaInfer,
             "schema-export" | "se" => Self::SchemaExport,
+            "print-schema" | "psc" => Self::PrintSchema,
+            "schema-validate" | "sv" => Self::SchemaValidate,
             "history" | "y" => Self::History,
-            _ => {
-                Self::None
-
-                // TODO
-                // Disabled error branch for current compatiblity
-                //return Err(CedError::CommandError(format!(
-                //"{} is not a valid command type",
-                //src
-                //)))
+            "" => Self::None,
+            src => {
+                let message = match utils::closest_match(src, COMMAND_NAMES) {
+                    Some(suggestion) => format!(
+                        "Unknown command : '{}', did you mean '{}'?",
+                        src, suggestion
+                    ),
+                    None => format!("Unknown command : '{}'", src),
+                };
+                return Err(CedError::CommandError(message));
             }
         };
         Ok(command_type)
@@ -136,12 +333,27 @@ impl Default for Command {
 impl FromStr for Command {
     type Err = CedError;
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        let src: Vec<String> = utils::tokens_with_quote(src);
-        let command = &src[0];
+        let tokens: Vec<String> = utils::tokens_with_quote(src);
+        // Blank source is a none command
+        let command = tokens.first().map(|s| s.as_str()).unwrap_or("");
         let command_type = CommandType::from_str(command)?;
+        // Double quotes are only kept for arguments parsed as csv
+        let is_csv_arguments = match command_type {
+            CommandType::AddRow | CommandType::EditRow | CommandType::EditCell => true,
+            CommandType::Schema => tokens
+                .get(1)
+                .map(|arg| arg.starts_with(SCHEMA_HEADER))
+                .unwrap_or(false),
+            _ => false,
+        };
+        let tokens = if is_csv_arguments {
+            tokens
+        } else {
+            utils::tokens_without_double_quote(src)
+        };
         Ok(Self {
             command_type,
-            arguments: src[1..].iter().map(|s| s.to_string()).collect(),
+            arguments: tokens.into_iter().skip(1).collect(),
         })
     }
 }
@@ -177,6 +389,13 @@ impl CommandHistory {
         } else {
             HISTORY_CAPACITY
         };
+        Self::with_capacity(capacity)
+    }
+
+    /// Create history with a given capacity
+    ///
+    /// This ignores "CED_HISTORY_CAPACITY" variable
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
             index: 0, // 0 should mean nothing rather than "first" element
             newest_snapshot: None,
@@ -217,6 +436,13 @@ impl CommandHistory {
         }
     }
 
+    /// Remove every history
+    pub(crate) fn clear(&mut self) {
+        self.index = 0;
+        self.memento_history.clear();
+        self.newest_snapshot.take();
+    }
+
     fn drain_history(&mut self) {
         if !self.memento_history.is_empty() && self.index < self.memento_history.len() {
             self.memento_history.drain(self.index..);
@@ -268,6 +494,20 @@ impl HistoryRecord {
 impl Processor {
     /// Execute given command
     pub fn execute_command(&mut self, command: &Command) -> CedResult<()> {
+        let substituted;
+        let command = if command
+            .arguments
+            .iter()
+            .any(|arg| utils::variable_reference(arg).is_some())
+        {
+            substituted = Command {
+                command_type: command.command_type,
+                arguments: self.substitute_variables(&command.arguments)?,
+            };
+            &substituted
+        } else {
+            command
+        };
         let page_name = &self
             .get_cursor()
             .ok_or_else(|| CedError::CommandError("Current page is empty".to_string()))?;
@@ -280,15 +520,57 @@ impl Processor {
             CommandType::Import => {
                 #[cfg(feature = "cli")]
                 self.drop_pages()?;
-                self.import_file_from_args(&command.arguments, false)?
+                self.import_file_from_args(&command.arguments, false, self.delimiter)?
+            }
+            CommandType::ImportRaw => {
+                self.import_file_from_args(&command.arguments, true, self.delimiter)?
+            }
+            CommandType::ImportTsv => {
+                #[cfg(feature = "cli")]
+                self.drop_pages()?;
+                self.import_file_from_args(&command.arguments, false, Some('\t'))?
+            }
+            CommandType::Append => self.append_from_args(page_name, &command.arguments)?,
+            CommandType::ImportStdin => {
+                #[cfg(feature = "cli")]
+                self.drop_pages()?;
+                self.import_stdin_from_args(&command.arguments)?
+            }
+            CommandType::DropPages => self.drop_pages_with_confirm()?,
+            CommandType::PageList => self.print_page_list()?,
+            CommandType::PageSwitch => self.page_switch_from_args(&command.arguments)?,
+            CommandType::PageRename => self.page_rename_from_args(&command.arguments)?,
+            CommandType::PageCopy => self.page_copy_from_args(&command.arguments)?,
+            CommandType::PageDrop => self.page_drop_from_args(&command.arguments)?,
+            CommandType::PageMerge => self.page_merge_from_args(&command.arguments)?,
+            CommandType::Join => self.join_from_args(&command.arguments)?,
+            CommandType::Diff => self.diff_from_args(&command.arguments)?,
+            CommandType::SnapshotSave => {
+                self.snapshot_save_from_args(page_name, &command.arguments)?
+            }
+            CommandType::SnapshotRestore => {
+                self.snapshot_restore_from_args(page_name, &command.arguments)?
             }
-            CommandType::ImportRaw => self.import_file_from_args(&command.arguments, true)?,
+            CommandType::SnapshotList => self.print_snapshot_list()?,
             CommandType::Schema => self.import_schema_from_args(page_name, &command.arguments)?,
             CommandType::SchemaInit => self.init_schema_from_args(&command.arguments)?,
+            CommandType::SchemaInfer => {
+                self.infer_schema_from_args(page_name, &command.arguments)?
+            }
             CommandType::SchemaExport => {
                 self.export_schema_from_args(page_name, &command.arguments)?
             }
+            CommandType::PrintSchema => self.print_schema(page_name)?,
+            CommandType::SchemaValidate => {
+                self.schema_validate_from_args(page_name, &command.arguments)?
+            }
             CommandType::Export => self.write_to_file_from_args(page_name, &command.arguments)?,
+            CommandType::ExportJson => self.export_json_from_args(page_name, &command.arguments)?,
+            CommandType::ExportJsonl => {
+                self.export_jsonl_from_args(page_name, &command.arguments)?
+            }
+            CommandType::ExportTsv => self.export_tsv_from_args(page_name, &command.arguments)?,
+            CommandType::ExportGz => self.export_gz_from_args(page_name, &command.arguments)?,
             CommandType::Write => {
                 self.overwrite_to_file_from_args(page_name, &command.arguments)?
             }
@@ -297,15 +579,42 @@ impl Processor {
                 self.log("New columns added\n")?;
             }
             CommandType::Print => self.print(page_name, &command.arguments)?,
+            CommandType::PrintCsv => self.export_to_stdout(page_name)?,
             CommandType::PrintCell => self.print_cell(page_name, &command.arguments)?,
             CommandType::PrintRow => self.print_row(page_name, &command.arguments)?,
+            CommandType::PrintRows => self.print_rows(page_name, &command.arguments)?,
             CommandType::PrintColumn => self.print_column(page_name, &command.arguments)?,
+            CommandType::PrintColumnsOnly => {
+                self.print_columns_only(page_name, &command.arguments)?
+            }
+            CommandType::MaskColumn => self.mask_column_from_args(page_name, &command.arguments)?,
+            CommandType::UnmaskColumn => {
+                self.unmask_column_from_args(page_name, &command.arguments)?
+            }
+            CommandType::MaskList => self.print_mask_list(page_name)?,
+            CommandType::Filter => self.filter_from_args(page_name, &command.arguments)?,
+            CommandType::Sample => self.sample_from_args(page_name, &command.arguments)?,
+            CommandType::Find => self.find_from_args(page_name, &command.arguments)?,
+            CommandType::Stats => self.stats_from_args(page_name, &command.arguments)?,
+            CommandType::Unique => self.unique_from_args(page_name, &command.arguments)?,
+            CommandType::Count => self.count_from_args(page_name, &command.arguments)?,
+            CommandType::Validate => self.validate_from_args(page_name)?,
             CommandType::AddRow => self.add_row_from_args(page_name, &command.arguments)?,
             CommandType::DeleteRow => self.remove_row_from_args(page_name, &command.arguments)?,
+            CommandType::DeleteRowsMatching => {
+                self.delete_rows_matching_from_args(page_name, &command.arguments)?
+            }
+            CommandType::Clear => {
+                let count = self.get_row_count(page_name)?;
+                self.clear_page(page_name)?;
+                self.log(&format!("Page cleared with \"{}\" rows removed\n", count))?;
+            }
+            CommandType::Dedupe => self.dedupe_from_args(page_name, &command.arguments)?,
             CommandType::DeleteColumn => {
                 self.remove_column_from_args(page_name, &command.arguments)?
             }
             CommandType::AddColumn => self.add_column_from_args(page_name, &command.arguments)?,
+            CommandType::CopyColumn => self.copy_column_from_args(page_name, &command.arguments)?,
             CommandType::EditCell => self.edit_cell_from_args(page_name, &command.arguments)?,
             CommandType::EditRow => self.edit_row_from_args(page_name, &command.arguments)?,
             #[cfg(feature = "cli")]
@@ -313,16 +622,39 @@ impl Processor {
                 self.edit_rows_from_args(page_name, &command.arguments)?
             }
             CommandType::EditColumn => self.edit_column_from_args(page_name, &command.arguments)?,
+            CommandType::ReplaceAll => self.replace_all_from_args(page_name, &command.arguments)?,
+            CommandType::FindReplace => {
+                self.find_replace_from_args(page_name, &command.arguments)?
+            }
+            CommandType::EditColumnRegex => {
+                self.edit_column_regex_from_args(page_name, &command.arguments)?
+            }
+            CommandType::EditCellsMatching => {
+                self.edit_cells_matching_from_args(page_name, &command.arguments)?
+            }
+            CommandType::Normalize => self.normalize_from_args(page_name, &command.arguments)?,
+            CommandType::FillDown => self.fill_down_from_args(page_name, &command.arguments)?,
+            CommandType::FillUp => self.fill_up_from_args(page_name, &command.arguments)?,
             CommandType::RenameColumn => {
                 self.rename_column_from_args(page_name, &command.arguments)?
             }
             CommandType::MoveRow => self.move_row_from_args(page_name, &command.arguments)?,
+            CommandType::CopyRow => self.copy_row_from_args(page_name, &command.arguments)?,
+            CommandType::SortRow => self.sort_row_from_args(page_name, &command.arguments)?,
+            CommandType::SwapRows => self.swap_rows_from_args(page_name, &command.arguments)?,
             CommandType::MoveColumn => self.move_column_from_args(page_name, &command.arguments)?,
+            CommandType::SwapColumns => {
+                self.swap_columns_from_args(page_name, &command.arguments)?
+            }
+            CommandType::Transpose => {
+                self.transpose(page_name)?;
+                self.log("Page transposed\n")?;
+            }
             CommandType::Limit => self.limit_column_from_args(page_name, &command.arguments)?,
-            #[cfg(feature = "cli")]
             CommandType::LimitPreset => self.limit_preset(page_name, &command.arguments)?,
             CommandType::Execute => self.execute_from_file(&command.arguments)?,
 
+            CommandType::Set => self.set_variable_from_args(&command.arguments)?,
             // NOTE
             // This is not handled by processor in current implementation
             CommandType::Exit | CommandType::Undo | CommandType::Redo | CommandType::History => (),
@@ -336,12 +668,8 @@ impl Processor {
                 "Insufficient arguments for move-row".to_string(),
             ));
         }
-        let src_number = args[0].parse::<usize>().map_err(|_| {
-            CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-        })?;
-        let target_number = args[1].parse::<usize>().map_err(|_| {
-            CedError::CommandError(format!("\"{}\" is not a valid row number", args[1]))
-        })?;
+        let src_number = utils::parse_arg::<usize>(&args[0], "row")?;
+        let target_number = utils::parse_arg::<usize>(&args[1], "row")?;
         self.move_row(page_name, src_number, target_number)?;
         self.log(&format!(
             "Row moved from \"{}\" to \"{}\"\n",
@@ -350,24 +678,69 @@ impl Processor {
         Ok(())
     }
 
+    fn copy_row_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for copy-row".to_string(),
+            ));
+        }
+        let src_number = utils::parse_arg::<usize>(&args[0], "row")?;
+        let target_number = utils::parse_arg::<usize>(&args[1], "row")?;
+        self.copy_row(page_name, src_number, target_number)?;
+        self.log(&format!(
+            "Row {} copied to position {}\n",
+            src_number, target_number
+        ))?;
+        Ok(())
+    }
+
+    fn swap_rows_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for swap-rows".to_string(),
+            ));
+        }
+        let a = utils::parse_arg::<usize>(&args[0], "row")?;
+        let b = utils::parse_arg::<usize>(&args[1], "row")?;
+        self.swap_rows(page_name, a, b)?;
+        self.log(&format!("Row \"{}\" and \"{}\" swapped\n", a, b))?;
+        Ok(())
+    }
+
+    fn sort_row_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for sort-row".to_string(),
+            ));
+        }
+        let column = &args[0];
+        let ascending = match args.get(1).map(|order| order.to_lowercase()).as_deref() {
+            None | Some("asc") => true,
+            Some("desc") => false,
+            Some(order) => {
+                return Err(CedError::CommandError(format!(
+                    "\"{}\" is not a valid sort order. Use either asc or desc",
+                    order
+                )))
+            }
+        };
+        self.sort_rows(page_name, column, ascending)?;
+        self.log(&format!(
+            "Rows sorted by \"{}\" in {} order\n",
+            column,
+            if ascending { "ascending" } else { "descending" }
+        ))?;
+        Ok(())
+    }
+
     fn move_column_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.len() < 2 {
             return Err(CedError::CommandError(
                 "Insufficient arguments for move-column".to_string(),
             ));
         }
-        let src_number = self
-            .get_page_data(page_name)?
-            .try_get_column_index(&args[0])
-            .ok_or_else(|| {
-                CedError::InvalidColumn(format!("Column : \"{}\" is not valid", args[0]))
-            })?;
-        let target_number = self
-            .get_page_data(page_name)?
-            .try_get_column_index(&args[1])
-            .ok_or_else(|| {
-                CedError::InvalidColumn(format!("Column : \"{}\" is not valid", args[1]))
-            })?;
+        let src_number = self.get_column_index(page_name, &args[0])?;
+        let target_number = self.get_column_index(page_name, &args[1])?;
         self.move_column(page_name, src_number, target_number)?;
         self.log(&format!(
             "Column moved from \"{}\" to \"{}\"\n",
@@ -376,6 +749,22 @@ impl Processor {
         Ok(())
     }
 
+    fn swap_columns_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for swap-columns".to_string(),
+            ));
+        }
+        let a = self.get_column_index(page_name, &args[0])?;
+        let b = self.get_column_index(page_name, &args[1])?;
+        self.swap_columns(page_name, a, b)?;
+        self.log(&format!(
+            "Column \"{}\" and \"{}\" swapped\n",
+            args[0], args[1]
+        ))?;
+        Ok(())
+    }
+
     fn rename_column_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.len() < 2 {
             return Err(CedError::CommandError(
@@ -409,9 +798,7 @@ impl Processor {
             1 => {
                 self.check_no_loop()?;
                 // Only row
-                row_number = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
+                row_number = utils::parse_arg::<usize>(&args[0], "row")?;
                 utils::write_to_stdout("Type comma(,) to exit input\n")?;
                 let values = self.edit_row_loop(page_name, Some(row_number))?;
                 if values.is_empty() {
@@ -421,13 +808,9 @@ impl Processor {
             }
             _ => {
                 // From 2.. row + data
-                row_number = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
-                let values = args[1]
-                    .split(DEFAULT_DELIMITER)
-                    .map(|s| s.to_string())
-                    .collect::<Vec<String>>();
+                row_number = utils::parse_arg::<usize>(&args[0], "row")?;
+                // Quoted fields may contain a delimiter
+                let values = dcsv::utils::csv_row_to_vector(&args[1], None, false);
                 self.set_row_from_string_array(page_name, row_number, &values)?;
             }
         }
@@ -448,18 +831,12 @@ impl Processor {
             0 => {}
             1 => {
                 // Only starting row
-                start_index = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
+                start_index = utils::parse_arg::<usize>(&args[0], "row")?;
             }
             _ => {
                 // From 2.. Starting row + ending row
-                start_index = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
-                end_index = args[1].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[1]))
-                })?;
+                start_index = utils::parse_arg::<usize>(&args[0], "row")?;
+                end_index = utils::parse_arg::<usize>(&args[1], "row")?;
             }
         }
 
@@ -505,6 +882,100 @@ impl Processor {
         Ok(())
     }
 
+    fn replace_all_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for replace-all".to_string(),
+            ));
+        }
+
+        let pattern = &args[0];
+        let replacement = &args[1];
+
+        let count = self.find_and_replace_all(page_name, pattern, replacement)?;
+        self.log(&format!(
+            "Replaced \"{}\" occurrences of \"{}\" with \"{}\"\n",
+            count, pattern, replacement
+        ))?;
+        Ok(())
+    }
+
+    fn find_replace_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 3 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for find-replace".to_string(),
+            ));
+        }
+
+        let column = &args[0];
+        let pattern = &args[1];
+        let replacement = &args[2];
+
+        let count = self.find_replace(page_name, Some(column), pattern, replacement)?;
+        self.log(&format!(
+            "Replaced \"{}\" occurrences of \"{}\" with \"{}\" in column \"{}\"\n",
+            count, pattern, replacement, column
+        ))?;
+        Ok(())
+    }
+
+    fn edit_cells_matching_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 4 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for edit-cells-matching".to_string(),
+            ));
+        }
+        let count = self.edit_cells_matching(page_name, &args[0], &args[1], &args[2], &args[3])?;
+        self.log(&format!(
+            "\"{}\" cells changed in column \"{}\"\n",
+            count, &args[2]
+        ))?;
+        Ok(())
+    }
+
+    fn edit_column_regex_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 3 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for edit-column-regex".to_string(),
+            ));
+        }
+        let count = self.edit_column_with_regex(page_name, &args[0], &args[1], &args[2])?;
+        self.log(&format!(
+            "\"{}\" cells changed in column \"{}\"\n",
+            count, &args[0]
+        ))?;
+        Ok(())
+    }
+
+    fn normalize_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let mut args = args.iter().map(|arg| arg.as_str()).collect::<Vec<_>>();
+        let case = match args.last() {
+            Some(&"upper") => Some(TextCase::Upper),
+            Some(&"lower") => Some(TextCase::Lower),
+            _ => None,
+        };
+        if case.is_some() {
+            args.pop();
+        }
+        let count = self.normalize(page_name, args.first().copied(), case)?;
+        self.log(&format!("\"{}\" cells normalized\n", count))?;
+        Ok(())
+    }
+
+    fn fill_down_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let column = args.first().map(|arg| arg.as_str());
+        let count = self.fill_down(page_name, column)?;
+        self.log(&format!("\"{}\" cells filled\n", count))?;
+        Ok(())
+    }
+
+    fn fill_up_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let column = args.first().map(|arg| arg.as_str());
+        let count = self.fill_up(page_name, column)?;
+        self.log(&format!("\"{}\" cells filled\n", count))?;
+        Ok(())
+    }
+
     fn edit_cell_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.is_empty() {
             return Err(CedError::CommandError("Edit needs coordinate".to_string()));
@@ -528,15 +999,8 @@ impl Processor {
             ));
         }
 
-        let row = coord[0].parse::<usize>().map_err(|_| {
-            CedError::CommandError(format!("\"{}\" is not a valid row number", coord[0]))
-        })?;
-        let column = self
-            .get_page_data(page_name)?
-            .try_get_column_index(coord[1])
-            .ok_or_else(|| {
-                CedError::InvalidColumn(format!("Column : \"{}\" is not valid", coord[1]))
-            })?;
+        let row = utils::parse_arg::<usize>(coord[0], "row")?;
+        let column = self.get_column_index(page_name, coord[1])?;
 
         self.edit_cell(page_name, row, column, &value)?;
         self.log(&format!(
@@ -572,9 +1036,7 @@ impl Processor {
             1 => {
                 self.check_no_loop()?;
                 // Only row number
-                row_number = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
+                row_number = utils::parse_arg::<usize>(&args[0], "row")?;
                 if row_number > self.get_row_count(page_name)? {
                     return Err(CedError::InvalidColumn(format!(
                         "Cannot add row to out of range position : {}",
@@ -590,13 +1052,9 @@ impl Processor {
             }
             _ => {
                 // From 2.. row + data
-                row_number = args[0].parse::<usize>().map_err(|_| {
-                    CedError::CommandError(format!("\"{}\" is not a valid row number", args[0]))
-                })?;
-                let values = args[1]
-                    .split(DEFAULT_DELIMITER)
-                    .map(|s| s.to_string())
-                    .collect::<Vec<String>>();
+                row_number = utils::parse_arg::<usize>(&args[0], "row")?;
+                // Quoted fields may contain a delimiter
+                let values = dcsv::utils::csv_row_to_vector(&args[1], None, false);
                 self.add_row_from_string_array(page_name, row_number, &values)?;
             }
         }
@@ -626,6 +1084,36 @@ impl Processor {
         false
     }
 
+    /// Create a prompt for loop variants
+    ///
+    /// Prompt includes column type and limiter information
+    #[cfg(feature = "cli")]
+    fn loop_prompt(col: &Column, default: &Value) -> String {
+        let limiter = if utils::is_bool_column(col) {
+            String::from("[true/false]")
+        } else if let Some(variants) = col.limiter.get_variant() {
+            format!(
+                "[{}]",
+                variants
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join("|")
+            )
+        } else if let Some(pattern) = col.limiter.get_pattern() {
+            format!("/{}/", pattern)
+        } else {
+            String::new()
+        };
+        format!(
+            "{} ({}){} ~{{{}}} = ",
+            col.name,
+            col.get_column_type(),
+            limiter,
+            default
+        )
+    }
+
     #[cfg(feature = "cli")]
     fn check_no_loop(&self) -> CedResult<()> {
         if self.no_loop {
@@ -643,7 +1131,7 @@ impl Processor {
         row_number: Option<usize>,
     ) -> CedResult<Vec<Value>> {
         let mut values = vec![];
-        let columns = &self.get_page_data(page_name)?.get_columns();
+        let columns = self.get_page_data(page_name)?.get_columns();
         if columns.is_empty() {
             utils::write_to_stdout(": Csv is empty : \n")?;
             return Ok(vec![]);
@@ -657,10 +1145,10 @@ impl Processor {
             } else {
                 col.get_default_value()
             };
-            utils::write_to_stdout(&format!("{}~{{{}}} = ", col.name, default))?;
+            utils::write_to_stdout(&Self::loop_prompt(col, &default))?;
             let value_src = utils::read_stdin(true)?;
             let mut value = if !value_src.is_empty() {
-                match Value::from_str(&value_src, col.column_type) {
+                match utils::column_value(col, &value_src) {
                     Ok(value) => value,
                     Err(_) => {
                         type_mismatch = true;
@@ -681,10 +1169,10 @@ impl Processor {
                 utils::write_to_stdout(
                     "Given value doesn't qualify column limiter or is not a valid csv value\n",
                 )?;
-                utils::write_to_stdout(&format!("{}~{{{}}} = ", col.name, default))?;
+                utils::write_to_stdout(&Self::loop_prompt(col, &default))?;
                 let value_src = utils::read_stdin(true)?;
                 value = if !value_src.is_empty() {
-                    match Value::from_str(&value_src, col.column_type) {
+                    match utils::column_value(col, &value_src) {
                         Ok(value) => value,
                         Err(_) => {
                             type_mismatch = true;
@@ -714,7 +1202,7 @@ impl Processor {
         row_number: Option<usize>,
     ) -> CedResult<Vec<Option<Value>>> {
         let mut values = vec![];
-        let columns = &self.get_page_data(page_name)?.get_columns();
+        let columns = self.get_page_data(page_name)?.get_columns();
         if columns.is_empty() {
             utils::write_to_stdout(": Csv is empty : \n")?;
             return Ok(vec![]);
@@ -728,10 +1216,10 @@ impl Processor {
             } else {
                 col.get_default_value()
             };
-            utils::write_to_stdout(&format!("{}~{{{}}} = ", col.name, default))?;
+            utils::write_to_stdout(&Self::loop_prompt(col, &default))?;
             let value_src = utils::read_stdin(true)?;
             let mut value = if !value_src.is_empty() {
-                Some(match Value::from_str(&value_src, col.column_type) {
+                Some(match utils::column_value(col, &value_src) {
                     Ok(value) => value,
                     Err(_) => {
                         type_mismatch = true;
@@ -757,10 +1245,10 @@ impl Processor {
                 }
                 type_mismatch = false;
                 utils::write_to_stdout("Given value doesn't qualify column limiter\n")?;
-                utils::write_to_stdout(&format!("{}~{{{}}} = ", col.name, default))?;
+                utils::write_to_stdout(&Self::loop_prompt(col, &default))?;
                 let value_src = utils::read_stdin(true)?;
                 value = if !value_src.is_empty() {
-                    Some(match Value::from_str(&value_src, col.column_type) {
+                    Some(match utils::column_value(col, &value_src) {
                         Ok(value) => value,
                         Err(_) => {
                             type_mismatch = true;
@@ -799,9 +1287,7 @@ impl Processor {
         let column_name = args[0].as_str();
 
         if args.len() >= 2 {
-            column_number = args[1].parse::<usize>().map_err(|_| {
-                CedError::CommandError(format!("\"{}\" is not a valid column number", args[1]))
-            })?;
+            column_number = utils::parse_arg::<usize>(&args[1], "column")?;
         }
         if args.len() >= 3 {
             column_type = ValueType::from_str(&args[2])?;
@@ -826,13 +1312,55 @@ impl Processor {
         Ok(())
     }
 
+    fn copy_column_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for copy-column".to_string(),
+            ));
+        }
+        let column_number = if args.len() >= 3 {
+            Some(utils::parse_arg::<usize>(&args[2], "column")?)
+        } else {
+            None
+        };
+        self.copy_column(page_name, &args[0], &args[1], column_number)?;
+        self.log(&format!(
+            "Column \"{}\" copied to \"{}\"\n",
+            args[0], args[1]
+        ))?;
+        Ok(())
+    }
+
     fn remove_row_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
+        // Inclusive range of rows
+        if args.len() >= 2 {
+            let start = utils::parse_arg::<usize>(&args[0], "row")?;
+            let end = utils::parse_arg::<usize>(&args[1], "row")?;
+            let row_count = self.get_row_count(page_name)?;
+            if start >= row_count {
+                return Err(CedError::CommandError(format!(
+                    "Row \"{}\" is out of range. Page has \"{}\" rows",
+                    start, row_count
+                )));
+            }
+            if start > end || end >= row_count {
+                return Err(CedError::CommandError(format!(
+                    "Row range \"{}\" to \"{}\" is not valid for \"{}\" rows",
+                    start, end, row_count
+                )));
+            }
+            let count = self.remove_row_range(page_name, start, end)?;
+            self.log(&format!(
+                "\"{}\" rows removed from \"{}\" to \"{}\"\n",
+                count, start, end
+            ))?;
+            return Ok(());
+        }
+
         let row_count = if args.is_empty() {
             self.get_row_count(page_name)?
         } else {
-            args[0].parse::<usize>().map_err(|_| {
-                CedError::CommandError(format!("\"{}\" is not a valid index", args[0]))
-            })?
+            utils::parse_arg::<usize>(&args[0], "row")?
         }
         .sub(1);
 
@@ -843,6 +1371,61 @@ impl Processor {
         Ok(())
     }
 
+    fn delete_rows_matching_from_args(
+        &mut self,
+        page_name: &str,
+        args: &[String],
+    ) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for delete-rows-matching".to_string(),
+            ));
+        }
+        let dry_run = match args.get(2).map(|arg| arg.as_str()) {
+            None => false,
+            Some("dry-run") => args
+                .get(3)
+                .map(|value| value.parse::<bool>())
+                .unwrap_or(Ok(true))
+                .map_err(|_| {
+                    CedError::CommandError(
+                        "You need to feed boolean value for the dry-run value".to_string(),
+                    )
+                })?,
+            Some(arg) => {
+                return Err(CedError::CommandError(format!(
+                    "Unknown argument \"{}\" for delete-rows-matching",
+                    arg
+                )))
+            }
+        };
+
+        if dry_run {
+            let matched = self.find_matching_rows(page_name, &args[0], &args[1])?;
+            let indices = matched
+                .iter()
+                .map(|index| index.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            utils::write_to_stdout(&format!(
+                "\"{}\" rows would be deleted : [{}]\n",
+                matched.len(),
+                indices
+            ))?;
+        } else {
+            let count = self.delete_rows_matching(page_name, &args[0], &args[1])?;
+            self.log(&format!("\"{}\" rows deleted\n", count))?;
+        }
+        Ok(())
+    }
+
+    fn dedupe_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let key_column = args.first().map(|arg| arg.as_str());
+        let count = self.deduplicate(page_name, key_column)?;
+        self.log(&format!("{} duplicate rows removed\n", count))?;
+        Ok(())
+    }
+
     fn remove_column_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         let column_count = if args.is_empty() {
             self.get_page_data(page_name)?.get_column_count()
@@ -876,14 +1459,42 @@ impl Processor {
     ///
     /// file is asssumed to have header
     /// You can give has_header value as second parameter
-    fn import_file_from_args(&mut self, args: &Vec<String>, raw_mode: bool) -> CedResult<()> {
+    fn append_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "You have to specify a file name to append from".to_owned(),
+            ));
+        }
+        let has_header = match args.get(1) {
+            Some(arg) => arg.parse().map_err(|_| {
+                CedError::CommandError(format!(
+                    "Given value \"{}\" should be a valid boolean value. ( has_header )",
+                    arg
+                ))
+            })?,
+            None => true,
+        };
+        let count = self.append_from_file(page_name, Path::new(&args[0]), has_header)?;
+        self.log(&format!(
+            "Appended \"{}\" rows from \"{}\"\n",
+            count, args[0]
+        ))?;
+        Ok(())
+    }
+
+    fn import_file_from_args(
+        &mut self,
+        args: &Vec<String>,
+        raw_mode: bool,
+        delimiter: Option<char>,
+    ) -> CedResult<()> {
         match args.len() {
             0 => {
                 return Err(CedError::CommandError(
                     "You have to specify a file name to import from".to_owned(),
                 ))
             }
-            1 => self.import_from_file(Path::new(&args[0]), true, None, raw_mode)?,
+            1 => self.import_from_file(Path::new(&args[0]), true, None, raw_mode, delimiter)?,
             _ => {
                 // Optional line ending configuration
                 let mut line_ending = None;
@@ -891,6 +1502,12 @@ impl Processor {
                     line_ending = Some('\r');
                 }
 
+                // Optional delimiter configuration
+                let delimiter = match args.get(3) {
+                    Some(delimiter) => Some(utils::parse_delimiter(delimiter)?),
+                    None => delimiter,
+                };
+
                 // Remove entry if already exists
                 let page_name = &args[0];
                 self.remove_page(page_name);
@@ -905,6 +1522,7 @@ impl Processor {
                     })?,
                     line_ending,
                     raw_mode,
+                    delimiter,
                 )?
             }
         }
@@ -913,56 +1531,332 @@ impl Processor {
         Ok(())
     }
 
-    fn import_schema_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
-        if self.get_page_data(page_name)?.is_array() {
-            return Err(CedError::InvalidPageOperation(
-                "Cannot import schema in array mode".to_string(),
+    fn import_stdin_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        let parse_bool = |arg: &String, name: &str| {
+            arg.parse::<bool>().map_err(|_| {
+                CedError::CommandError(format!(
+                    "Given value \"{}\" should be a valid boolean value. ( {} )",
+                    arg, name
+                ))
+            })
+        };
+        let has_header = match args.first() {
+            Some(arg) => parse_bool(arg, "has_header")?,
+            None => true,
+        };
+        let raw_mode = match args.get(1) {
+            Some(arg) => parse_bool(arg, "raw_mode")?,
+            None => false,
+        };
+        self.import_from_stdin(has_header, raw_mode)?;
+        let footer = if raw_mode { " as array mode" } else { "" };
+        self.log(&format!("Stdin imported{}\n", footer))?;
+        Ok(())
+    }
+
+    fn page_switch_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Page-switch requires a page name".to_owned(),
             ));
         }
+        if !self.change_cursor(&args[0]) {
+            return Err(CedError::InvalidPageOperation(format!(
+                "Page \"{}\" doesn't exist",
+                args[0]
+            )));
+        }
+        self.log(&format!("Switched to page \"{}\"\n", args[0]))?;
+        Ok(())
+    }
 
+    fn page_rename_from_args(&mut self, args: &[String]) -> CedResult<()> {
         if args.len() < 2 {
             return Err(CedError::CommandError(
-                "Insufficient variable for schema".to_owned(),
+                "Insufficient arguments for page-rename".to_string(),
             ));
         }
-        let schema_file = &args[0];
-        let force = &args[1];
-        self.set_schema(
-            page_name,
-            schema_file,
-            !force
-                .parse::<bool>()
-                .map_err(|_| CedError::CommandError(format!("{} is not a valid value", force)))?,
-        )?;
-        self.log(&format!("Schema \"{}\" applied\n", &args[0]))?;
+        self.rename_page(&args[0], &args[1])?;
+        self.log(&format!(
+            "Page renamed from \"{}\" to \"{}\"\n",
+            args[0], args[1]
+        ))?;
         Ok(())
     }
 
-    fn export_schema_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
-        if self.get_page_data(page_name)?.is_array() {
-            return Err(CedError::InvalidPageOperation(
-                "Cannot export schema in array mode".to_string(),
+    fn page_copy_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for page-copy".to_string(),
             ));
         }
+        self.copy_page(&args[0], &args[1])?;
+        self.log(&format!("Page \"{}\" copied to \"{}\"\n", args[0], args[1]))?;
+        Ok(())
+    }
 
+    fn page_drop_from_args(&mut self, args: &[String]) -> CedResult<()> {
         if args.is_empty() {
             return Err(CedError::CommandError(
-                "Schema export needs a file path".to_owned(),
+                "Page-drop requires a page name".to_owned(),
             ));
         }
-        let file = &args[0];
-        let mut file = std::fs::File::create(file)
-            .map_err(|err| CedError::io_error(err, "Failed to open file for schema write"))?;
-
-        file.write_all(self.export_schema(page_name)?.as_bytes())
-            .map_err(|err| CedError::io_error(err, "Failed to write schema to a file"))?;
-
-        self.log(&format!("Schema exported to \"{}\"\n", args[0]))?;
+        let page = self.get_page_data(&args[0])?;
+        let (rows, columns) = (page.get_row_count(), page.get_column_count());
+        self.remove_page(&args[0]);
+        self.log(&format!(
+            "Page \"{}\" dropped with \"{}\" rows and \"{}\" columns\n",
+            args[0], rows, columns
+        ))?;
         Ok(())
     }
 
-    fn init_schema_from_args(&mut self, args: &Vec<String>) -> CedResult<()> {
-        let file_name = if args.is_empty() {
+    fn diff_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for diff".to_string(),
+            ));
+        }
+        let diff = self.diff_pages(&args[0], &args[1])?;
+        if diff.is_empty() {
+            self.log("No differences found\n")?;
+            return Ok(());
+        }
+        let mut output = String::new();
+        for row_index in &diff.removed {
+            let row = self
+                .get_page_data(&args[0])?
+                .get_row_as_string(*row_index)?;
+            output.push_str(&format!("- [{}] {}\n", row_index, row));
+        }
+        for row_index in &diff.added {
+            let row = self
+                .get_page_data(&args[1])?
+                .get_row_as_string(*row_index)?;
+            output.push_str(&format!("+ [{}] {}\n", row_index, row));
+        }
+        for (row_index, column_index, old, new) in &diff.changed {
+            output.push_str(&format!(
+                "~ ({},{}) \"{}\" -> \"{}\"\n",
+                row_index, column_index, old, new
+            ));
+        }
+        utils::write_to_stdout(&output)?;
+        self.log(&format!("\"{}\" differences found\n", diff.len()))?;
+        #[cfg(feature = "cli")]
+        {
+            self.check_failed = true;
+        }
+        Ok(())
+    }
+
+    fn join_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.len() < 4 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for join".to_string(),
+            ));
+        }
+        let count = self.left_join(&args[0], &args[1], &args[2], &args[3])?;
+        self.log(&format!(
+            "Joined \"{}\" rows into \"{}\"\n",
+            count, &args[3]
+        ))?;
+        Ok(())
+    }
+
+    fn page_merge_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for page-merge".to_string(),
+            ));
+        }
+        let strict = match args.get(2) {
+            Some(strict) => strict.parse::<bool>().map_err(|_| {
+                CedError::CommandError(format!("\"{}\" is not a valid boolean value", strict))
+            })?,
+            None => false,
+        };
+        let count = self.merge_pages(&args[0], &args[1], strict)?;
+        self.change_cursor(&args[1]);
+        self.log(&format!(
+            "\"{}\" rows merged from \"{}\" into \"{}\"\n",
+            count, args[0], args[1]
+        ))?;
+        Ok(())
+    }
+
+    fn snapshot_save_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Snapshot-save requires a snapshot name".to_owned(),
+            ));
+        }
+        self.save_snapshot(page_name, &args[0])?;
+        self.log(&format!("Snapshot \"{}\" saved\n", args[0]))?;
+        Ok(())
+    }
+
+    fn snapshot_restore_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Snapshot-restore requires a snapshot name".to_owned(),
+            ));
+        }
+        self.restore_snapshot(page_name, &args[0])?;
+        self.log(&format!("Snapshot \"{}\" restored\n", args[0]))?;
+        Ok(())
+    }
+
+    fn print_snapshot_list(&self) -> CedResult<()> {
+        let snapshots = self.list_snapshots();
+        if snapshots.is_empty() {
+            utils::write_to_stdout(": No snapshots :\n")?;
+            return Ok(());
+        }
+        for snapshot in snapshots {
+            utils::write_to_stdout(&format!(
+                "{} : rows={} columns={}\n",
+                snapshot.name, snapshot.rows, snapshot.columns
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn print_page_list(&self) -> CedResult<()> {
+        let pages = self.list_pages();
+        let name_width = pages
+            .iter()
+            .map(|page| page.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("NAME".len());
+        utils::write_to_stdout(&format!(
+            "   {: <name_width$} | ROWS | COLUMNS | MODE  | SOURCE\n",
+            "NAME"
+        ))?;
+        for page in pages {
+            utils::write_to_stdout(&format!(
+                "{} {: <name_width$} | {: <4} | {: <7} | {: <5} | {}\n",
+                if page.is_cursor { "->" } else { "  " },
+                page.name,
+                page.rows,
+                page.columns,
+                if page.is_array { "array" } else { "data" },
+                page.source_file.unwrap_or_default()
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Ask user for a confirmation
+    ///
+    /// This always returns true without a prompt if no_confirm is set
+    #[cfg(feature = "cli")]
+    pub(crate) fn confirm(&self, prompt: &str) -> CedResult<bool> {
+        if self.no_confirm {
+            return Ok(true);
+        }
+        utils::write_to_stdout(&format!("{} (y/N) : ", prompt))?;
+        Ok(utils::read_stdin(true)?.to_lowercase().as_str() == "y")
+    }
+
+    fn drop_pages_with_confirm(&mut self) -> CedResult<()> {
+        // Confirm only in interactive mode
+        #[cfg(feature = "cli")]
+        if !self.no_loop && self.has_unsaved_pages() && !self.confirm("Discard all pages?")? {
+            return Ok(());
+        }
+        self.drop_pages()?;
+        self.log("All pages dropped\n")?;
+        Ok(())
+    }
+
+    fn import_schema_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
+        if self.get_page_data(page_name)?.is_array() {
+            return Err(CedError::InvalidPageOperation(
+                "Cannot import schema in array mode".to_string(),
+            ));
+        }
+
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient variable for schema".to_owned(),
+            ));
+        }
+        let schema = &args[0];
+        // Force is always the last argument
+        let force = &args[args.len() - 1];
+        let panic = !force
+            .parse::<bool>()
+            .map_err(|_| CedError::CommandError(format!("{} is not a valid value", force)))?;
+
+        // Inline schema is given as rows of arguments starting with a header
+        if schema.starts_with(SCHEMA_HEADER) {
+            let content = args[..args.len() - 1].join("\n");
+            self.set_schema_from_string(page_name, &content, panic)?;
+            self.log("Inline schema applied\n")?;
+            return Ok(());
+        }
+
+        self.set_schema(page_name, schema, panic)?;
+        self.log(&format!("Schema \"{}\" applied\n", &args[0]))?;
+        Ok(())
+    }
+
+    fn print_schema(&self, page_name: &str) -> CedResult<()> {
+        let schema = self.export_schema(page_name)?;
+        let mut lines = schema.lines();
+        let header = lines.next().unwrap_or(SCHEMA_HEADER);
+        let rows = lines.collect::<Vec<_>>();
+        let digits_count = rows.len().to_string().len();
+        let format_row = |row: &str| {
+            dcsv::utils::csv_row_to_vector(row, None, false)
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("[{}]:{}", i, field))
+                .collect::<Vec<_>>()
+                .join("")
+        };
+        utils::write_to_stdout(&format!(
+            "{: <digits_count$} | {}\n",
+            "H",
+            format_row(header)
+        ))?;
+        for (index, row) in rows.iter().enumerate() {
+            utils::write_to_stdout(&format!(
+                "{: <digits_count$} | {}\n",
+                index,
+                format_row(row)
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn export_schema_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
+        if self.get_page_data(page_name)?.is_array() {
+            return Err(CedError::InvalidPageOperation(
+                "Cannot export schema in array mode".to_string(),
+            ));
+        }
+
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Schema export needs a file path".to_owned(),
+            ));
+        }
+        let file = &args[0];
+        let mut file = std::fs::File::create(file)
+            .map_err(|err| CedError::io_error(err, "Failed to open file for schema write"))?;
+
+        file.write_all(self.export_schema(page_name)?.as_bytes())
+            .map_err(|err| CedError::io_error(err, "Failed to write schema to a file"))?;
+
+        self.log(&format!("Schema exported to \"{}\"\n", args[0]))?;
+        Ok(())
+    }
+
+    fn init_schema_from_args(&mut self, args: &Vec<String>) -> CedResult<()> {
+        let file_name = if args.is_empty() {
             "ced_schema.csv"
         } else {
             &args[0]
@@ -977,17 +1871,91 @@ impl Processor {
         Ok(())
     }
 
+    fn infer_schema_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let file_name = if args.is_empty() {
+            "ced_schema.csv"
+        } else {
+            &args[0]
+        };
+        let schema = self.infer_schema(page_name)?;
+        std::fs::write(file_name, schema.as_bytes())
+            .map_err(|err| CedError::io_error(err, "Failed to write schema to a file"))?;
+
+        self.log(&format!("Schema inferred to \"{}\"\n", file_name))?;
+        Ok(())
+    }
+
     fn write_to_file_from_args(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.is_empty() {
             return Err(CedError::CommandError(
                 "Export requires file path".to_owned(),
             ));
         }
-        self.write_to_file(page_name, &args[0])?;
+        let delimiter = match args.get(1) {
+            Some(delimiter) => Some(utils::parse_delimiter(delimiter)?),
+            None => self.delimiter,
+        };
+        self.write_to_file(page_name, &args[0], delimiter)?;
         self.log(&format!("File exported to \"{}\"\n", &args[0]))?;
         Ok(())
     }
 
+    fn export_jsonl_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let jsonl = self.export_as_jsonl(page_name)?;
+        match args.first() {
+            Some(file) => {
+                std::fs::write(file, jsonl.as_bytes())
+                    .map_err(|err| CedError::io_error(err, "Failed to write json lines to file"))?;
+                self.log(&format!("File exported to \"{}\" as json lines\n", file))?;
+            }
+            None => utils::write_to_stdout(&jsonl)?,
+        }
+        Ok(())
+    }
+
+    fn export_tsv_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Export-tsv requires file path".to_owned(),
+            ));
+        }
+        self.write_to_file(page_name, &args[0], Some('\t'))?;
+        self.log(&format!("File exported to \"{}\" as tsv\n", &args[0]))?;
+        Ok(())
+    }
+
+    #[cfg(feature = "gz")]
+    fn export_gz_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Export-gz requires file path".to_owned(),
+            ));
+        }
+        self.write_to_file_gz(page_name, &args[0])?;
+        self.log(&format!("File exported to \"{}\" as gzip\n", &args[0]))?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "gz"))]
+    fn export_gz_from_args(&mut self, _page_name: &str, _args: &[String]) -> CedResult<()> {
+        Err(CedError::CommandError(
+            "Export-gz requires \"gz\" feature".to_owned(),
+        ))
+    }
+
+    fn export_json_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Export-json requires file path".to_owned(),
+            ));
+        }
+        let json = self.export_as_json(page_name)?;
+        std::fs::write(&args[0], json.as_bytes())
+            .map_err(|err| CedError::io_error(err, "Failed to write json content to file"))?;
+        self.log(&format!("File exported to \"{}\" as json\n", &args[0]))?;
+        Ok(())
+    }
+
     fn overwrite_to_file_from_args(
         &mut self,
         page_name: &str,
@@ -1000,7 +1968,11 @@ impl Processor {
         } else {
             true
         };
-        let success = self.overwrite_to_file(page_name, cache)?;
+        let delimiter = match args.get(1) {
+            Some(delimiter) => Some(utils::parse_delimiter(delimiter)?),
+            None => self.delimiter,
+        };
+        let success = self.overwrite_to_file(page_name, cache, delimiter)?;
         if success {
             self.log("File overwritten successfully\n")?;
         } else {
@@ -1018,11 +1990,11 @@ impl Processor {
         if !args.is_empty() {
             viewer = args[0..].to_vec();
         } else if let Ok(var) = std::env::var("CED_VIEWER") {
-            viewer = utils::tokens_with_quote(&var);
+            viewer = utils::tokens_without_double_quote(&var);
         }
 
         if viewer.is_empty() {
-            self.print_virtual_container(page_name)?;
+            self.print_virtual_container(page_name, None)?;
         } else {
             let csv = self.get_page_as_string(page_name)?;
             self.print_with_viewer(csv, &viewer)?;
@@ -1031,6 +2003,188 @@ impl Processor {
         Ok(())
     }
 
+    fn filter_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for filter".to_string(),
+            ));
+        }
+        let keep = match args.get(2).map(|arg| arg.as_str()) {
+            None => false,
+            Some("keep") => true,
+            Some(arg) => {
+                return Err(CedError::CommandError(format!(
+                    "Unknown argument \"{}\" for filter",
+                    arg
+                )))
+            }
+        };
+        let matched = self.filter_rows(page_name, &args[0], &args[1], keep)?;
+        if keep {
+            self.log(&format!("Kept \"{}\" matching rows\n", matched.len()))?;
+        } else if matched.is_empty() {
+            utils::write_to_stdout(": No matching rows :\n")?;
+        } else {
+            self.print_virtual_container(page_name, Some(&matched))?;
+        }
+        Ok(())
+    }
+
+    fn find_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let (column, query) = match args.len() {
+            0 => {
+                return Err(CedError::CommandError(
+                    "Insufficient arguments for find".to_string(),
+                ))
+            }
+            1 => (None, &args[0]),
+            _ => (Some(args[0].as_str()), &args[1]),
+        };
+        let use_regex = match args.get(2).map(|arg| arg.as_str()) {
+            None => false,
+            Some("regex") => true,
+            Some(arg) => {
+                return Err(CedError::CommandError(format!(
+                    "Unknown argument \"{}\" for find",
+                    arg
+                )))
+            }
+        };
+        let found = self.find_rows(page_name, column, query, use_regex)?;
+        if found.is_empty() {
+            utils::write_to_stdout(": No matching rows :\n")?;
+        }
+        for row_index in found {
+            self.print_virtual_data_row(page_name, row_index, false)?;
+        }
+        Ok(())
+    }
+
+    fn count_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let use_regex = match args.get(2).map(|arg| arg.as_str()) {
+            None => false,
+            Some("regex") => true,
+            Some(arg) => {
+                return Err(CedError::CommandError(format!(
+                    "Unknown argument \"{}\" for count",
+                    arg
+                )))
+            }
+        };
+        let count = self.count_matching(
+            page_name,
+            args.first().map(|arg| arg.as_str()),
+            args.get(1).map(|arg| arg.as_str()),
+            use_regex,
+        )?;
+        utils::write_to_stdout(&format!("{}\n", count))?;
+        Ok(())
+    }
+
+    fn unique_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Insufficient arguments for unique".to_string(),
+            ));
+        }
+        let with_count = match args.get(1).map(|arg| arg.as_str()) {
+            None => false,
+            Some("count") => true,
+            Some(arg) => {
+                return Err(CedError::CommandError(format!(
+                    "Unknown argument \"{}\" for unique",
+                    arg
+                )))
+            }
+        };
+        let mut output = String::new();
+        for (value, count) in self.unique_value_counts(page_name, &args[0])? {
+            if with_count {
+                output.push_str(&format!("{} : {} rows\n", value, count));
+            } else {
+                output.push_str(&format!("{}\n", value));
+            }
+        }
+        utils::write_to_stdout(&output)?;
+        Ok(())
+    }
+
+    fn sample_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Sample needs count of rows".to_string(),
+            ));
+        }
+        let count = utils::parse_arg::<usize>(&args[0], "count")?;
+        let mut keep = false;
+        let mut seed = None;
+        for arg in &args[1..] {
+            match arg.as_str() {
+                "keep" | "--keep" => keep = true,
+                arg => seed = Some(utils::parse_arg::<u64>(arg, "seed")?),
+            }
+        }
+        let sampled = self.sample_rows(page_name, count, seed)?;
+        if keep {
+            self.retain_rows(page_name, &sampled)?;
+            self.log(&format!("Kept \"{}\" sampled rows\n", sampled.len()))?;
+        } else {
+            self.print_virtual_container(page_name, Some(&sampled))?;
+        }
+        Ok(())
+    }
+
+    fn stats_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        let columns = if args.is_empty() {
+            self.get_page_data(page_name)?
+                .get_columns()
+                .iter()
+                .map(|col| col.name.clone())
+                .collect::<Vec<_>>()
+        } else {
+            vec![args[0].clone()]
+        };
+        for column in columns {
+            let stats = self.column_stats(page_name, &column)?;
+            utils::write_to_stdout(&format!("{} : {}\n", column, stats))?;
+        }
+        Ok(())
+    }
+
+    fn validate_from_args(&mut self, page_name: &str) -> CedResult<()> {
+        let errors = self.validate_page(page_name)?;
+        self.print_validation_errors(&errors)
+    }
+
+    fn schema_validate_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Schema-validate requires schema file".to_owned(),
+            ));
+        }
+        let errors = self.validate_against_schema(page_name, &args[0])?;
+        self.print_validation_errors(&errors)
+    }
+
+    /// Print validation errors
+    ///
+    /// Violations are not an error of a command but make --command exit with a non-zero code
+    fn print_validation_errors(&mut self, errors: &[ValidationError]) -> CedResult<()> {
+        if errors.is_empty() {
+            self.log("No violations found\n")?;
+            return Ok(());
+        }
+        for error in errors {
+            utils::write_to_stdout(&format!("{}\n", error))?;
+        }
+        self.log(&format!("\"{}\" violations found\n", errors.len()))?;
+        #[cfg(feature = "cli")]
+        {
+            self.check_failed = true;
+        }
+        Ok(())
+    }
+
     fn print_cell(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.is_empty() {
             return Err(CedError::CommandError(
@@ -1046,9 +2200,7 @@ impl Processor {
             )));
         }
         let (x, y) = (
-            coord[0].parse::<usize>().map_err(|_| {
-                CedError::CommandError("You need to feed usize number for coordinate".to_string())
-            })?,
+            utils::parse_arg::<usize>(coord[0], "row")?,
             self.get_page_data(page_name)?
                 .try_get_column_index(coord[1])
                 .ok_or_else(|| {
@@ -1086,9 +2238,7 @@ impl Processor {
                 "Print-row needs row number".to_string(),
             ));
         }
-        let row_index = args[0].parse::<usize>().map_err(|_| {
-            CedError::CommandError("You need to feed a valid number as a row number".to_string())
-        })?;
+        let row_index = utils::parse_arg::<usize>(&args[0], "row")?;
 
         let row = self
             .get_page_data(page_name)?
@@ -1110,6 +2260,111 @@ impl Processor {
         }
     }
 
+    fn print_rows(&self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Print-rows needs start row number".to_string(),
+            ));
+        }
+        let row_count = self.get_row_count(page_name)?;
+        let start = utils::parse_arg::<usize>(&args[0], "row")?;
+        // Second argument is a viewer if it is not a number
+        let (end, viewer_index) = match args.get(1).map(|arg| arg.parse::<usize>()) {
+            Some(Ok(end)) => (end, 2),
+            _ => (row_count.saturating_sub(1), 1),
+        };
+        if start > end || end >= row_count {
+            return Err(CedError::CommandError(format!(
+                "Rows \"{}..={}\" are out of range. Page has \"{}\" rows",
+                start, end, row_count
+            )));
+        }
+
+        let mut viewer = vec![];
+        // Use given command
+        // or use environment variable
+        // External command has higher priority
+        if args.len() > viewer_index {
+            viewer = args[viewer_index..].to_vec();
+        } else if let Ok(var) = std::env::var("CED_VIEWER") {
+            viewer = var.split_whitespace().map(|s| s.to_string()).collect();
+        }
+
+        if !viewer.is_empty() {
+            let rows = (start..=end).collect::<Vec<_>>();
+            let csv = self
+                .get_page_data(page_name)?
+                .to_string_with_rows(',', Some(&rows));
+            return subprocess(&viewer, Some(csv));
+        }
+        for row_index in start..=end {
+            self.print_virtual_data_row(page_name, row_index, row_index == start)?;
+        }
+        Ok(())
+    }
+
+    fn print_columns_only(&self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Print-columns-only needs column names".to_string(),
+            ));
+        }
+        let columns = args[0].split(',').map(|s| s.trim()).collect::<Vec<_>>();
+        let csv = self.get_page_as_string_with_columns(page_name, &columns)?;
+
+        let viewer: Vec<_>;
+        // Use given command
+        // or use environment variable
+        // External command has higher priority
+        if args.len() >= 2 {
+            viewer = args[1..].to_vec();
+            subprocess(&viewer, Some(csv))
+        } else if let Ok(var) = std::env::var("CED_VIEWER") {
+            viewer = var.split_whitespace().map(|s| s.to_string()).collect();
+            subprocess(&viewer, Some(csv))
+        } else {
+            utils::write_to_stdout(&csv)
+        }
+    }
+
+    fn mask_column_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Mask-column needs a column name".to_string(),
+            ));
+        }
+        if self.mask_column(page_name, &args[0])? {
+            self.log(&format!("Column \"{}\" masked\n", args[0]))?;
+        } else {
+            self.log(&format!("Column \"{}\" is already masked\n", args[0]))?;
+        }
+        Ok(())
+    }
+
+    fn unmask_column_from_args(&mut self, page_name: &str, args: &[String]) -> CedResult<()> {
+        if args.is_empty() {
+            return Err(CedError::CommandError(
+                "Unmask-column needs a column name".to_string(),
+            ));
+        }
+        if self.unmask_column(page_name, &args[0])? {
+            self.log(&format!("Column \"{}\" unmasked\n", args[0]))?;
+        } else {
+            self.log(&format!("Column \"{}\" is not masked\n", args[0]))?;
+        }
+        Ok(())
+    }
+
+    fn print_mask_list(&self, page_name: &str) -> CedResult<()> {
+        let masked = self.get_masked_columns(page_name)?;
+        if masked.is_empty() {
+            utils::write_to_stdout(": No masked columns :\n")?;
+        } else {
+            utils::write_to_stdout(&format!("{}\n", masked.join("\n")))?;
+        }
+        Ok(())
+    }
+
     fn print_column(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         let mut print_mode = "simple";
         if args.is_empty() {
@@ -1202,7 +2457,14 @@ impl Processor {
     }
 
     /// Print virtual container to console
-    fn print_virtual_container(&self, page_name: &str) -> CedResult<()> {
+    ///
+    /// Only given rows are printed if rows are supplied
+    fn print_virtual_container(&self, page_name: &str, filter: Option<&[usize]>) -> CedResult<()> {
+        if self.output_format != OutputFormat::Table {
+            let formatted = self.get_page_as_format(page_name, self.output_format, filter)?;
+            utils::write_to_stdout(&formatted)?;
+            return Ok(());
+        }
         let page = self.get_page_data(page_name)?;
         // Empty csv value, return early
         if page.get_row_count() == 0 {
@@ -1214,25 +2476,29 @@ impl Processor {
             utils::write_to_stdout("-- Mode: Array --\n")?;
         }
         let digits_count = page.get_row_count().to_string().len();
+        // Masked columns are skipped but indices are kept
+        let visible = page.get_visible_column_indices();
         // 0 length csv is panicking error at this moment, thus safe to unwrap
         let header_with_number = format!(
             "{: <digits_count$} | {}\n",
             "H",
-            page.get_columns()
+            visible
                 .iter()
-                .enumerate()
-                .map(|(i, col)| format!("[{}]:{}", i, col.name))
+                .map(|&i| format!("[{}]:{}", i, page.get_columns()[i].name))
                 .collect::<Vec<String>>()
                 .join("")
         );
         utils::write_to_stdout(&header_with_number)?;
 
         let rows = self.get_page_data(page_name)?.get_rows();
-        for (index, row) in rows.iter().enumerate() {
-            let row_string = row
+        for (index, row) in rows
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| filter.map(|rows| rows.contains(index)).unwrap_or(true))
+        {
+            let row_string = visible
                 .iter()
-                .enumerate()
-                .map(|(i, cell)| format!("[{}]:{}", i, cell))
+                .filter_map(|&i| row.get(i).map(|cell| format!("[{}]:{}", i, cell)))
                 .collect::<Vec<_>>()
                 .join("");
             utils::write_to_stdout(&format!("{: <digits_count$} | {}\n", index, row_string))?;
@@ -1286,7 +2552,6 @@ impl Processor {
         Ok(())
     }
 
-    #[cfg(feature = "cli")]
     pub fn limit_preset(&mut self, page_name: &str, args: &Vec<String>) -> CedResult<()> {
         if args.len() < 2 {
             return Err(CedError::CommandError(
@@ -1307,6 +2572,35 @@ impl Processor {
         Ok(())
     }
 
+    /// Substitute "$name" arguments with values of variables
+    fn substitute_variables(&self, args: &[String]) -> CedResult<Vec<String>> {
+        args.iter()
+            .map(|arg| match utils::variable_reference(arg) {
+                Some(name) => self
+                    .get_variable(name)
+                    .map(|v| v.to_owned())
+                    .ok_or_else(|| {
+                        CedError::CommandError(format!("Variable \"{}\" is not defined", name))
+                    }),
+                None => Ok(arg.to_owned()),
+            })
+            .collect()
+    }
+
+    fn set_variable_from_args(&mut self, args: &[String]) -> CedResult<()> {
+        if args.len() < 2 {
+            return Err(CedError::CommandError(
+                "Set requires a name and a value".to_owned(),
+            ));
+        }
+        self.set_variable(&args[0], &args[1])?;
+        self.log(&format!(
+            "Variable \"{}\" set to \"{}\"\n",
+            args[0], args[1]
+        ))?;
+        Ok(())
+    }
+
     pub fn execute_from_file(&mut self, args: &Vec<String>) -> CedResult<()> {
         if args.is_empty() {
             return Err(CedError::CommandError(
@@ -1320,11 +2614,25 @@ impl Processor {
                 &format!("Failed to read file \"{}\" for execution", file),
             )
         })?;
+        self.execute_script_from_string(&content)
+    }
+
+    /// Execute script text as command sequence
+    ///
+    /// Commands are separated by newlines or semicolons. Text after "#" is a comment if it
+    /// starts a command. Execution stops at the first failed command.
+    pub fn execute_script_from_string(&mut self, script: &str) -> CedResult<()> {
         // Split by line
-        for (idx, line) in content.lines().enumerate() {
+        for (idx, line) in script.lines().enumerate() {
+            // Comment lasts until the end of a line
+            let line = utils::strip_comment(line);
             // Split by semi colon
             for comm in line.split_terminator(';') {
-                if let Err(err) = self.execute_command(&Command::from_str(comm)?) {
+                if comm.trim().is_empty() {
+                    continue;
+                }
+                let result = Command::from_str(comm).and_then(|c| self.execute_command(&c));
+                if let Err(err) = result {
                     utils::write_to_stderr(&format!(
                         "Line : {} -> Failed to execute command : \"{}\"\n",
                         idx + 1,
@@ -0,0 +1,25 @@
+use dcsv::Value;
+
+/// Differences between two pages
+///
+/// Rows are compared by index. Added rows are indices of the new page and removed rows are
+/// indices of the old page.
+#[derive(Debug, Clone, Default)]
+pub struct PageDiff {
+    pub added: Vec<usize>,
+    pub removed: Vec<usize>,
+    /// Row index, column index, old value and new value
+    pub changed: Vec<(usize, usize, Value, Value)>,
+}
+
+impl PageDiff {
+    /// Whether there is no difference
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Count of all differences
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+}
@@ -6,11 +6,18 @@ pub type CedResult<T> = Result<T, CedError>;
 pub enum CedError {
     CommandError(String),
     CsvDataError(DcsvError),
+    InvalidCellData {
+        row: usize,
+        column: String,
+        value: String,
+        reason: String,
+    },
     InvalidColumn(String),
     InvalidPageOperation(String),
     InvalidRowData(String),
     IoError(IoErrorWithMeta),
     OutOfRangeError,
+    ParseError(String),
 }
 
 impl std::fmt::Display for CedError {
@@ -18,6 +25,16 @@ impl std::fmt::Display for CedError {
         match self {
             Self::CommandError(txt) => write!(f, "ERR : Invalid command call =\n{0}", txt),
             Self::CsvDataError(err) => write!(f, "{err}"),
+            Self::InvalidCellData {
+                row,
+                column,
+                value,
+                reason,
+            } => write!(
+                f,
+                "ERR : Invalid cell data =\nRow {0}, column '{1}': '{2}' :: {3}",
+                row, column, value, reason
+            ),
             Self::InvalidColumn(txt) => write!(f, "ERR : Invalid column =\n{0}", txt),
             Self::InvalidPageOperation(txt) => {
                 write!(f, "ERR : Invalid page operation =\n{0}", txt)
@@ -25,6 +42,18 @@ impl std::fmt::Display for CedError {
             Self::InvalidRowData(txt) => write!(f, "ERR : Invalid row data =\n{0}", txt),
             Self::IoError(io_error) => write!(f, "ERR : IO Error =\n{0}", io_error),
             Self::OutOfRangeError => write!(f, "ERR : Index out of range"),
+            Self::ParseError(txt) => write!(f, "ERR : Failed to parse value =\n{0}", txt),
+        }
+    }
+}
+
+impl std::error::Error for CedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            // DcsvError doesn't implement std error trait
+            Self::CsvDataError(_) => None,
+            Self::IoError(err) => Some(err),
+            _ => None,
         }
     }
 }
@@ -35,6 +64,18 @@ impl From<DcsvError> for CedError {
     }
 }
 
+impl From<std::num::ParseIntError> for CedError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::ParseError(err.to_string())
+    }
+}
+
+impl From<std::num::ParseFloatError> for CedError {
+    fn from(err: std::num::ParseFloatError) -> Self {
+        Self::ParseError(err.to_string())
+    }
+}
+
 impl CedError {
     pub fn io_error(err: std::io::Error, meta: &str) -> Self {
         Self::IoError(IoErrorWithMeta::new(err, meta))
@@ -66,3 +107,9 @@ impl std::fmt::Display for IoErrorWithMeta {
         write!(f, "{} :: {}", self.error, self.meta)
     }
 }
+
+impl std::error::Error for IoErrorWithMeta {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
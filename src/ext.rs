@@ -0,0 +1,127 @@
+use crate::utils;
+use crate::{CedError, CedResult};
+use dcsv::{Column, Value, ValueLimiter, ValueType};
+
+/// Typed constructors for a limiter
+///
+/// ```rust
+/// use ced::LimiterExt;
+/// use dcsv::{Value, ValueLimiter, ValueType};
+///
+/// let any_number = ValueLimiter::from_type(ValueType::Number);
+/// let bool_text = ValueLimiter::from_variants(
+///     Value::Text("false".to_owned()),
+///     vec![Value::Text("true".to_owned()), Value::Text("false".to_owned())],
+/// )
+/// .expect("Failed to create limiter");
+/// ```
+pub trait LimiterExt: Sized {
+    /// Create a limiter which accepts any value of a type
+    fn from_type(value_type: ValueType) -> Self;
+
+    /// Create a limiter which accepts only given variants
+    ///
+    /// Type is decided by default value. Default should be one of variants.
+    fn from_variants(default: Value, variants: Vec<Value>) -> CedResult<Self>;
+
+    /// Create a limiter which accepts values matching a regex pattern
+    ///
+    /// Default should be a type of value_type and match the pattern.
+    fn from_pattern(value_type: ValueType, default: Value, pattern: &str) -> CedResult<Self>;
+}
+
+impl LimiterExt for ValueLimiter {
+    fn from_type(value_type: ValueType) -> Self {
+        let mut limiter = Self::default();
+        limiter.set_type(value_type);
+        limiter
+    }
+
+    fn from_variants(default: Value, variants: Vec<Value>) -> CedResult<Self> {
+        let value_type = default.get_type();
+        if let Some(variant) = variants.iter().find(|v| v.get_type() != value_type) {
+            return Err(CedError::InvalidRowData(format!(
+                "Variant \"{}\" is not a type of \"{}\"",
+                variant, value_type
+            )));
+        }
+        if !variants.contains(&default) {
+            return Err(CedError::InvalidRowData(format!(
+                "Default \"{}\" is not one of variants",
+                default
+            )));
+        }
+        let mut limiter = Self::from_type(value_type);
+        limiter.set_variant(default, &variants)?;
+        Ok(limiter)
+    }
+
+    fn from_pattern(value_type: ValueType, default: Value, pattern: &str) -> CedResult<Self> {
+        let pattern = utils::build_regex(pattern)?;
+        if default.get_type() != value_type || !pattern.is_match(&default.to_string()) {
+            return Err(CedError::InvalidRowData(format!(
+                "Default \"{}\" doesn't qualify pattern \"{}\"",
+                default, pattern
+            )));
+        }
+        let mut limiter = Self::from_type(value_type);
+        limiter.set_pattern(default, pattern)?;
+        Ok(limiter)
+    }
+}
+
+/// Builder methods for a column
+///
+/// ```rust
+/// use ced::{ColumnExt, LimiterExt};
+/// use dcsv::{Column, Value, ValueLimiter, ValueType};
+///
+/// let age = Column::new("age", ValueType::Text, None)
+///     .with_limiter(ValueLimiter::from_type(ValueType::Number))
+///     .with_default(Value::Number(0))
+///     .expect("Failed to set default");
+/// ```
+pub trait ColumnExt {
+    /// Set a limiter. Column type follows limiter's type
+    fn with_limiter(self, limiter: ValueLimiter) -> Self;
+
+    /// Set a default value of column's limiter
+    ///
+    /// Default should qualify limiter's variants or pattern if limiter has them.
+    fn with_default(self, default: Value) -> CedResult<Self>
+    where
+        Self: Sized;
+}
+
+impl ColumnExt for Column {
+    fn with_limiter(mut self, limiter: ValueLimiter) -> Self {
+        self.column_type = limiter.get_type();
+        self.limiter = limiter;
+        self
+    }
+
+    fn with_default(mut self, default: Value) -> CedResult<Self> {
+        if default.get_type() != self.limiter.get_type() {
+            return Err(CedError::InvalidRowData(format!(
+                "Default \"{}\" is not a type of \"{}\"",
+                default,
+                self.limiter.get_type()
+            )));
+        }
+        // Limiter only sets a default along with variants or a pattern
+        if let Some(variants) = self.limiter.get_variant().cloned() {
+            self.limiter.set_variant(default, &variants)?;
+        } else if let Some(pattern) = self.limiter.get_pattern().cloned() {
+            self.limiter.set_pattern(default, pattern)?;
+        } else {
+            let value_type = self.limiter.get_type().to_string();
+            self.limiter = ValueLimiter::from_line(&[
+                value_type,
+                default.to_string(),
+                String::new(),
+                String::new(),
+            ])?;
+        }
+        Ok(self)
+    }
+}
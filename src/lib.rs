@@ -86,17 +86,28 @@ mod test;
 pub(crate) mod cli;
 
 pub(crate) mod command;
+pub(crate) mod diff;
 pub(crate) mod utils;
 
 pub(crate) mod error;
+pub(crate) mod ext;
 pub(crate) mod page;
+pub(crate) mod preset;
 pub(crate) mod processor;
+pub(crate) mod stats;
+pub(crate) mod validation;
 
 // ----------
 // RE-EXPORTS
 
 #[cfg(feature = "cli")]
-pub use cli::command_loop::start_main_loop;
+pub use cli::command_loop::{start_main_loop, CommandLoop};
 pub use command::{Command, CommandType};
+pub use diff::PageDiff;
 pub use error::{CedError, CedResult};
-pub use processor::Processor;
+pub use ext::{ColumnExt, LimiterExt};
+pub use page::PageInfo;
+pub use preset::Preset;
+pub use processor::{OutputFormat, Processor, ProcessorBuilder, TextCase};
+pub use stats::ColumnStats;
+pub use validation::{ValidationError, ValueRange};
@@ -1,15 +1,34 @@
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
-use dcsv::{Column, VCont, Value, ValueLimiter, ValueType, VirtualArray, VirtualData};
+use dcsv::{Column, DcsvError, VCont, Value, ValueLimiter, ValueType, VirtualArray, VirtualData};
 
+use crate::utils;
+use crate::validation::ValueRange;
 use crate::{CedError, CedResult};
 
 #[derive(Clone)]
 pub(crate) struct Page {
     pub(crate) source_file: Option<PathBuf>,
+    /// Columns hidden from display
+    pub(crate) masked_columns: HashSet<String>,
+    /// Ranges of columns checked by validation
+    pub(crate) ranges: HashMap<String, ValueRange>,
     content: PageContent,
 }
 
+/// Summary information of a page
+#[derive(Debug, Clone)]
+pub struct PageInfo {
+    pub name: String,
+    pub rows: usize,
+    pub columns: usize,
+    pub is_array: bool,
+    pub source_file: Option<String>,
+    /// Whether page is a current cursor
+    pub is_cursor: bool,
+}
+
 #[derive(Clone)]
 pub(crate) enum PageContent {
     Data(VirtualData),
@@ -34,6 +53,8 @@ impl Page {
     pub fn new_data(data: VirtualData) -> Self {
         Self {
             source_file: None,
+            masked_columns: HashSet::new(),
+            ranges: HashMap::new(),
             content: PageContent::Data(data),
         }
     }
@@ -41,9 +62,45 @@ impl Page {
     pub fn new_array(array: VirtualArray) -> Self {
         Self {
             source_file: None,
+            masked_columns: HashSet::new(),
+            ranges: HashMap::new(),
             content: PageContent::Array(array),
         }
     }
+    /// Hide a column from display
+    ///
+    /// # Return
+    ///
+    /// Whether column was newly masked
+    pub fn mask_column(&mut self, column: &str) -> CedResult<bool> {
+        if self.try_get_column_index(column).is_none() {
+            return Err(CedError::InvalidColumn(format!(
+                "Column : \"{}\" is not valid",
+                column
+            )));
+        }
+        Ok(self.masked_columns.insert(column.to_owned()))
+    }
+
+    /// Show a masked column again
+    ///
+    /// # Return
+    ///
+    /// Whether column was masked
+    pub fn unmask_column(&mut self, column: &str) -> bool {
+        self.masked_columns.remove(column)
+    }
+
+    /// Get indices of columns which are not masked
+    pub fn get_visible_column_indices(&self) -> Vec<usize> {
+        self.get_columns()
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| !self.masked_columns.contains(&column.name))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     pub fn move_column(&mut self, src_index: usize, target_index: usize) -> CedResult<()> {
         match &mut self.content {
             PageContent::Data(data) => data.move_column(src_index, target_index)?,
@@ -52,6 +109,56 @@ impl Page {
         Ok(())
     }
 
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> CedResult<()> {
+        if a >= self.get_column_count() || b >= self.get_column_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        match &mut self.content {
+            // Rows are keyed by column name, thus only column order changes
+            PageContent::Data(data) => data.columns.swap(a, b),
+            PageContent::Array(array) => {
+                array.columns.swap(a, b);
+                for row in array.rows.iter_mut() {
+                    row.swap(a, b);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Transpose rows and columns
+    ///
+    /// Original column names become values of the first column and each row becomes a column
+    /// named "row_N". Every new column is a text column.
+    pub fn transpose(&mut self) -> CedResult<()> {
+        let data = match &mut self.content {
+            PageContent::Data(data) => data,
+            PageContent::Array(_) => {
+                return Err(CedError::InvalidPageOperation(
+                    "Cannot transpose virtual array".to_string(),
+                ))
+            }
+        };
+        let mut transposed = VirtualData::new();
+        transposed.insert_column(0, "column")?;
+        for row_index in 0..data.get_row_count() {
+            transposed.insert_column(row_index + 1, &format!("row_{}", row_index))?;
+        }
+        for (column_index, column) in data.columns.iter().enumerate() {
+            let mut values = vec![Value::Text(column.name.clone())];
+            for row_index in 0..data.get_row_count() {
+                let value = data
+                    .get_cell(row_index, column_index)
+                    .map(|value| value.to_string())
+                    .unwrap_or_default();
+                values.push(Value::Text(value));
+            }
+            transposed.insert_row(column_index, Some(&values))?;
+        }
+        *data = transposed;
+        Ok(())
+    }
+
     pub fn move_row(&mut self, src_index: usize, target_index: usize) -> CedResult<()> {
         match &mut self.content {
             PageContent::Data(data) => data.move_row(src_index, target_index)?,
@@ -60,6 +167,74 @@ impl Page {
         Ok(())
     }
 
+    /// Copy a row into a destination index
+    ///
+    /// Row is cloned as it is, thus limiters are not checked again
+    pub fn copy_row(&mut self, src_index: usize, dest_index: usize) -> CedResult<()> {
+        if src_index >= self.get_row_count() || dest_index > self.get_row_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        match &mut self.content {
+            PageContent::Data(data) => {
+                let row = data.rows[src_index].clone();
+                data.rows.insert(dest_index, row);
+            }
+            PageContent::Array(array) => {
+                let row = array.rows[src_index].clone();
+                array.rows.insert(dest_index, row);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> CedResult<()> {
+        if a >= self.get_row_count() || b >= self.get_row_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        match &mut self.content {
+            PageContent::Data(data) => data.rows.swap(a, b),
+            PageContent::Array(array) => array.rows.swap(a, b),
+        }
+        Ok(())
+    }
+
+    /// Sort rows by values of a given column
+    ///
+    /// Sort is stable, thus rows with equal values keep their original order
+    pub fn sort_rows(&mut self, column_index: usize, ascending: bool) -> CedResult<()> {
+        if column_index >= self.get_column_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        // Decimal texts, such as values limited by "float" preset, are sorted numerically
+        let is_decimal = (0..self.get_row_count())
+            .filter_map(|row_index| self.get_cell(row_index, column_index))
+            .filter(|value| matches!(value, Value::Text(text) if !text.is_empty()))
+            .all(|value| utils::parse_decimal(value).is_some());
+        let mut order = (0..self.get_row_count()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let ordering = match (
+                self.get_cell(a, column_index),
+                self.get_cell(b, column_index),
+            ) {
+                (Some(lhs), Some(rhs)) if is_decimal => utils::compare_decimals(lhs, rhs),
+                (Some(lhs), Some(rhs)) => utils::compare_values(lhs, rhs),
+                _ => std::cmp::Ordering::Equal,
+            };
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        match &mut self.content {
+            PageContent::Data(data) => data.rows = reorder(std::mem::take(&mut data.rows), &order),
+            PageContent::Array(array) => {
+                array.rows = reorder(std::mem::take(&mut array.rows), &order)
+            }
+        }
+        Ok(())
+    }
+
     pub fn delete_row(&mut self, row_index: usize) -> bool {
         match &mut self.content {
             PageContent::Data(data) => data.delete_row(row_index),
@@ -68,6 +243,10 @@ impl Page {
     }
 
     pub fn delete_column(&mut self, column_index: usize) -> CedResult<()> {
+        if let Some(column) = self.get_columns().get(column_index) {
+            let name = column.name.clone();
+            self.ranges.remove(&name);
+        }
         match &mut self.content {
             PageContent::Data(data) => data.delete_column(column_index)?,
             PageContent::Array(array) => array.delete_column(column_index)?,
@@ -100,29 +279,71 @@ impl Page {
     }
 
     pub fn insert_row(&mut self, row_index: usize, source: Option<&[Value]>) -> CedResult<()> {
+        let normalized = source.map(|values| self.normalize_bools(values));
         match &mut self.content {
-            PageContent::Data(data) => data.insert_row(row_index, source)?,
+            PageContent::Data(data) => {
+                if let Err(err) = data.insert_row(row_index, normalized.as_deref()) {
+                    let values = normalized.iter().flatten().map(Some);
+                    return Err(invalid_row_data(data, row_index, values, err));
+                }
+            }
             PageContent::Array(array) => array.insert_row(row_index, source)?,
         }
         Ok(())
     }
 
     pub fn edit_row(&mut self, row_index: usize, values: &[Option<Value>]) -> CedResult<()> {
+        let normalized = values
+            .iter()
+            .enumerate()
+            .map(
+                |(index, value)| match (value, self.get_columns().get(index)) {
+                    (Some(value), Some(column)) => {
+                        Some(utils::normalize_bool(column, value.clone()))
+                    }
+                    _ => value.clone(),
+                },
+            )
+            .collect::<Vec<_>>();
         match &mut self.content {
-            PageContent::Data(data) => data.edit_row(row_index, values)?,
+            PageContent::Data(data) => {
+                if let Err(err) = data.edit_row(row_index, &normalized) {
+                    let values = normalized.iter().map(Option::as_ref);
+                    return Err(invalid_row_data(data, row_index, values, err));
+                }
+            }
             PageContent::Array(array) => array.edit_row(row_index, values)?,
         }
         Ok(())
     }
 
     pub fn rename_column(&mut self, column_index: usize, new_name: &str) -> CedResult<()> {
+        let old_name = self.get_columns().get(column_index).map(|c| c.name.clone());
         match &mut self.content {
             PageContent::Data(data) => data.rename_column(column_index, new_name)?,
             PageContent::Array(array) => array.rename_column(column_index, new_name)?,
         }
+        // Range follows a renamed column
+        if let Some(range) = old_name.and_then(|name| self.ranges.remove(&name)) {
+            self.ranges.insert(new_name.to_owned(), range);
+        }
         Ok(())
     }
 
+    /// Normalize bool literals of values for bool columns
+    ///
+    /// Values are matched to columns by position
+    fn normalize_bools(&self, values: &[Value]) -> Vec<Value> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| match self.get_columns().get(index) {
+                Some(column) => utils::normalize_bool(column, value.clone()),
+                None => value.clone(),
+            })
+            .collect()
+    }
+
     pub fn get_data(&self) -> Option<&VirtualData> {
         match &self.content {
             PageContent::Data(data) => Some(data),
@@ -150,8 +371,14 @@ impl Page {
     }
 
     pub fn set_row(&mut self, row_index: usize, values: &[Value]) -> CedResult<()> {
+        let normalized = self.normalize_bools(values);
         match &mut self.content {
-            PageContent::Data(data) => data.set_row(row_index, values)?,
+            PageContent::Data(data) => {
+                if let Err(err) = data.set_row(row_index, &normalized) {
+                    let values = normalized.iter().map(Some);
+                    return Err(invalid_row_data(data, row_index, values, err));
+                }
+            }
             PageContent::Array(array) => array.set_row(row_index, values)?,
         }
         Ok(())
@@ -196,26 +423,26 @@ impl Page {
     }
 
     pub fn get_rows(&self) -> Vec<Vec<&Value>> {
+        self.rows_iter().collect()
+    }
+
+    /// Iterate rows lazily, yielding values in column order
+    pub fn rows_iter(&self) -> Box<dyn Iterator<Item = Vec<&Value>> + '_> {
         match &self.content {
-            PageContent::Data(data) => {
-                let mut values_outer = vec![];
-                for row in &data.rows {
+            PageContent::Data(data) => Box::new(
+                data.rows
+                    .iter()
                     // THis operation cannot fail
-                    let values_inner = row.to_vector(&data.columns).unwrap();
-                    values_outer.push(values_inner);
-                }
-                values_outer
-            }
+                    .map(|row| row.to_vector(&data.columns).unwrap()),
+            ),
             // Though this is very archaic... single entry is hard to achive
-            PageContent::Array(array) => array
-                .rows
-                .iter()
-                .map(|row| row.iter().collect::<Vec<_>>())
-                .collect::<Vec<_>>(),
+            PageContent::Array(array) => {
+                Box::new(array.rows.iter().map(|row| row.iter().collect::<Vec<_>>()))
+            }
         }
     }
 
-    pub fn get_columns(&self) -> &Vec<Column> {
+    pub fn get_columns(&self) -> &[Column] {
         match &self.content {
             PageContent::Data(data) => &data.columns,
             PageContent::Array(array) => &array.columns,
@@ -242,14 +469,74 @@ impl Page {
         }
     }
 
+    pub fn set_cell(&mut self, x: usize, y: usize, value: Value) -> CedResult<()> {
+        let value = match self.get_columns().get(y) {
+            Some(column) => utils::normalize_bool(column, value),
+            None => value,
+        };
+        match &mut self.content {
+            PageContent::Data(data) => {
+                let src = value.to_string();
+                data.set_cell(x, y, value)
+                    .map_err(|err| invalid_cell_data(data, x, y, &src, err))?
+            }
+            PageContent::Array(array) => array.set_cell(x, y, value)?,
+        }
+        Ok(())
+    }
+
     pub fn set_cell_from_string(&mut self, x: usize, y: usize, value: &str) -> CedResult<()> {
+        let value = self
+            .get_columns()
+            .get(y)
+            .and_then(|column| utils::bool_literal(column, value))
+            .unwrap_or(value);
         match &mut self.content {
-            PageContent::Data(data) => data.set_cell_from_string(x, y, value)?,
+            PageContent::Data(data) => data
+                .set_cell_from_string(x, y, value)
+                .map_err(|err| invalid_cell_data(data, x, y, value, err))?,
             PageContent::Array(array) => array.set_cell(x, y, Value::Text(value.to_string()))?,
         }
         Ok(())
     }
 
+    /// Get page content as csv string with a given delimiter
+    ///
+    /// Fields that include delimiter, double quote or newline are quoted
+    pub fn to_string_with_delimiter(&self, delimiter: Option<char>) -> String {
+        match delimiter {
+            None | Some(',') => self.to_string(),
+            Some(delimiter) => self.to_string_with_rows(delimiter, None),
+        }
+    }
+
+    /// Get page content as csv string with only given rows
+    ///
+    /// Every row is included if rows is none
+    pub fn to_string_with_rows(&self, delimiter: char, rows: Option<&[usize]>) -> String {
+        let mut csv = self
+            .get_columns()
+            .iter()
+            .map(|col| utils::quote_field(&col.name, delimiter))
+            .collect::<Vec<_>>()
+            .join(&delimiter.to_string());
+        csv.push('\n');
+        for (_, row) in self
+            .rows_iter()
+            .enumerate()
+            .filter(|(index, _)| rows.map(|rows| rows.contains(index)).unwrap_or(true))
+        {
+            let row = row
+                .iter()
+                .map(|value| utils::quote_field(&value.to_string(), delimiter))
+                .collect::<Vec<_>>()
+                .join(&delimiter.to_string());
+            csv.push_str(&row);
+            csv.push('\n');
+        }
+        csv
+    }
+
     pub fn get_row_as_string(&self, row_index: usize) -> CedResult<String> {
         let string = match &self.content {
             PageContent::Data(data) => {
@@ -274,7 +561,63 @@ impl Page {
     }
 }
 
+/// Add cell's context to an error of cell operation
+///
+/// Error is returned as it is if coordinate is out of range
+fn invalid_cell_data(
+    data: &VirtualData,
+    x: usize,
+    y: usize,
+    value: &str,
+    err: DcsvError,
+) -> CedError {
+    match data.columns.get(y) {
+        Some(column) if !matches!(err, DcsvError::OutOfRangeError) => CedError::InvalidCellData {
+            row: x,
+            column: column.name.clone(),
+            value: value.to_owned(),
+            reason: err.to_string(),
+        },
+        _ => err.into(),
+    }
+}
+
+/// Add context of the first value that fails its column's limiter to an error of row operation
+///
+/// Error is returned as it is if every value qualifies
+fn invalid_row_data<'a>(
+    data: &VirtualData,
+    x: usize,
+    values: impl Iterator<Item = Option<&'a Value>>,
+    err: DcsvError,
+) -> CedError {
+    if !matches!(err, DcsvError::InvalidRowData(_)) {
+        return err.into();
+    }
+    let failed = data
+        .columns
+        .iter()
+        .zip(values)
+        .enumerate()
+        .find_map(|(y, (column, value))| {
+            value.filter(|v| !column.limiter.qualify(v)).map(|v| (y, v))
+        });
+    match failed {
+        Some((y, value)) => invalid_cell_data(data, x, y, &value.to_string(), err),
+        None => err.into(),
+    }
+}
+
 //match self.content {
 //PageContent::Data(data),
 //PageContent::Array(array),
 //}
+
+/// Rearrange items with given order of indices
+fn reorder<T>(source: Vec<T>, order: &[usize]) -> Vec<T> {
+    let mut source = source.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .iter()
+        .filter_map(|&index| source[index].take())
+        .collect()
+}
@@ -4,6 +4,7 @@ use std::{collections::HashMap, path::PathBuf};
 
 const PRESET_FILE_NAME: &str = ".ced_preset.csv";
 
+/// Named limiters which can be applied to a column
 pub struct Preset {
     list: HashMap<String, ValueLimiter>,
 }
@@ -19,6 +20,7 @@ impl Preset {
             list: HashMap::new(),
         };
         if use_defualt {
+            #[cfg(feature = "presets")]
             instance.append_default()?;
         }
 
@@ -62,6 +64,7 @@ impl Preset {
         Ok(limiters)
     }
 
+    #[cfg(feature = "presets")]
     fn append_default(&mut self) -> CedResult<()> {
         let default = IntoIterator::into_iter([
             (
@@ -76,6 +79,10 @@ impl Preset {
                 "float".to_owned(),
                 ValueLimiter::from_line(&["text", "0.0", "", r#"[+-]?([0-9]*[.])?[0-9]+"#])?,
             ),
+            (
+                "bool".to_owned(),
+                ValueLimiter::from_line(&["text", "false", "true false", ""])?,
+            ),
             (
                 "email".to_owned(),
                 ValueLimiter::from_line(&[
@@ -91,7 +98,7 @@ impl Preset {
                     "text",
                     "2000-01-01",
                     "",
-                    r#"([12]\d{3}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01]))"#,
+                    r#"^([12]\d{3}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01]))$"#,
                 ])?,
             ),
             (
@@ -112,6 +119,15 @@ impl Preset {
                     r#"[(http(s)?)://(www\.)?a-zA-Z0-9@:%._\+~#=]{2,256}\.[a-z]{2,6}\b([-a-zA-Z0-9@:%_\+.~#?&//=]*)"#,
                 ])?,
             ),
+            (
+                "uuid".to_owned(),
+                ValueLimiter::from_line(&[
+                    "text",
+                    "00000000-0000-4000-8000-000000000000",
+                    "",
+                    r#"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-4[0-9a-fA-F]{3}-[89abAB][0-9a-fA-F]{3}-[0-9a-fA-F]{12}$"#,
+                ])?,
+            ),
         ]);
         self.list.extend(default);
         Ok(())
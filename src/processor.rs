@@ -11,29 +11,74 @@
 /// use ced::Processor;
 /// let mut processor = Processor::new();
 ///
-/// processor.import_from_file("test.csv", true, None, false).unwrap();
+/// processor.import_from_file("test.csv", true, None, false, None).unwrap();
 ///
 /// // Get current cursor(page_name) for later uses
 /// let page_name = processor.get_cursor().unwrap();
 ///
 /// // Processor can hold multiple pages and needs page_name for every operation to work on the
 /// // page
-/// processor.add_row_from_string_array(&page_name, processor.last_row_index(&page_name)?, &["a","b"]).unwrap();
+/// processor.add_row_from_string_array(&page_name, processor.last_row_index(&page_name)?.unwrap_or(0), &["a","b"]).unwrap();
 ///
-/// processor.overwrite_to_file(&page_name,true).unwrap();
+/// processor.overwrite_to_file(&page_name,true,None).unwrap();
 /// ```
 use std::fs::File;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::Path;
 
-#[cfg(feature = "cli")]
-use crate::cli::preset::Preset;
+use crate::diff::PageDiff;
 use crate::error::{CedError, CedResult};
-use crate::page::Page;
+use crate::page::{Page, PageInfo};
+use crate::preset::Preset;
+use crate::stats::ColumnStats;
 use crate::utils;
-use dcsv::Column;
-use dcsv::{Value, ValueLimiter, ValueType};
-use std::collections::HashMap;
+use crate::validation::{ValidationError, ValueRange};
+use dcsv::{Column, VCont, VirtualData};
+use dcsv::{Value, ValueLimiter, ValueType, SCHEMA_HEADER};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+const STDIN_PAGE: &str = "stdin";
+
+/// Case conversion applied by normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextCase {
+    Upper,
+    Lower,
+}
+
+/// Format of printed page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+    Jsonl,
+    Tsv,
+    /// Numbered rows with column indices
+    #[default]
+    Table,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = CedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = match s.to_lowercase().as_str() {
+            "csv" => Self::Csv,
+            "json" => Self::Json,
+            "jsonl" => Self::Jsonl,
+            "tsv" => Self::Tsv,
+            "table" => Self::Table,
+            _ => {
+                return Err(CedError::ParseError(format!(
+                    "\"{}\" is not a valid output format",
+                    s
+                )))
+            }
+        };
+        Ok(format)
+    }
+}
 
 /// Csv processor
 ///
@@ -41,12 +86,27 @@ use std::collections::HashMap;
 /// selected page which name can be accessed with ```get_cursor``` method.
 pub struct Processor {
     pub(crate) pages: HashMap<String, Page>,
+    pub(crate) snapshots: HashMap<String, Page>,
     pub(crate) cursor: Option<String>,
     pub(crate) print_logs: bool,
-    #[cfg(feature = "cli")]
+    pub(crate) output_format: OutputFormat,
+    /// Default field delimiter of import and export commands
+    pub(crate) delimiter: Option<char>,
+    /// Variables substituted in command arguments
+    pub(crate) variables: HashMap<String, String>,
     preset: Preset,
+    /// Capacity of undo history used by a command loop. None follows CED_HISTORY_CAPACITY
+    pub(crate) history_capacity: Option<usize>,
     #[cfg(feature = "cli")]
     pub(crate) no_loop: bool,
+    /// Answer every confirmation prompt with yes
+    #[cfg(feature = "cli")]
+    pub(crate) no_confirm: bool,
+    /// Whether diff or validation found differences or violations
+    ///
+    /// Commands given with --command exit with a non-zero code if this is set
+    #[cfg(feature = "cli")]
+    pub(crate) check_failed: bool,
 }
 
 impl Default for Processor {
@@ -55,20 +115,116 @@ impl Default for Processor {
     }
 }
 
+/// Builder to configure a processor at construction time
+///
+/// ```rust
+/// use ced::ProcessorBuilder;
+///
+/// let processor = ProcessorBuilder::new()
+///     .print_logs(false)
+///     .history_capacity(32)
+///     .use_preset(true)
+///     .build();
+/// ```
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    print_logs: Option<bool>,
+    history_capacity: Option<usize>,
+    use_preset: bool,
+    #[cfg(feature = "cli")]
+    no_loop: bool,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether logs of commands are printed. Default is true
+    pub fn print_logs(mut self, enabled: bool) -> Self {
+        self.print_logs = Some(enabled);
+        self
+    }
+
+    /// Capacity of undo history. Default is CED_HISTORY_CAPACITY or 16
+    pub fn history_capacity(mut self, capacity: usize) -> Self {
+        self.history_capacity = Some(capacity);
+        self
+    }
+
+    /// Whether to load built-in and global presets. Default is false
+    pub fn use_preset(mut self, enabled: bool) -> Self {
+        self.use_preset = enabled;
+        self
+    }
+
+    /// Whether processor runs without an interactive loop. Default is false
+    #[cfg(feature = "cli")]
+    pub fn no_loop(mut self, enabled: bool) -> Self {
+        self.no_loop = enabled;
+        self
+    }
+
+    /// Build a processor
+    ///
+    /// A malformed global preset file doesn't fail a build but is warned and ignored. Use
+    /// configure_preset to handle the error.
+    pub fn build(self) -> Processor {
+        let mut processor = Processor::new();
+        if let Some(print_logs) = self.print_logs {
+            processor.set_print_logs(print_logs);
+        }
+        processor.history_capacity = self.history_capacity;
+        if self.use_preset {
+            if let Err(err) = processor.configure_preset(true) {
+                // Failing to write a warning is not critical either
+                let _ = utils::write_to_stderr(&format!("WRN : Presets are not loaded\n{}\n", err));
+            }
+        }
+        #[cfg(feature = "cli")]
+        {
+            processor.no_loop = self.no_loop;
+        }
+        processor
+    }
+}
+
 impl Processor {
     /// Create empty processor
     pub fn new() -> Self {
         Self {
             pages: HashMap::new(),
+            snapshots: HashMap::new(),
             cursor: None,
+            output_format: OutputFormat::default(),
+            delimiter: None,
+            variables: HashMap::new(),
             print_logs: true,
-            #[cfg(feature = "cli")]
             preset: Preset::empty(),
+            history_capacity: None,
             #[cfg(feature = "cli")]
             no_loop: false,
+            #[cfg(feature = "cli")]
+            no_confirm: false,
+            #[cfg(feature = "cli")]
+            check_failed: false,
         }
     }
 
+    /// Set whether logs of commands are printed
+    pub fn set_print_logs(&mut self, enabled: bool) -> &mut Self {
+        self.print_logs = enabled;
+        self
+    }
+
+    /// Create processor with given print logs option
+    ///
+    /// e.g. ```Processor::new().with_print_logs(false)```
+    pub fn with_print_logs(mut self, enabled: bool) -> Self {
+        self.print_logs = enabled;
+        self
+    }
+
     /// Change current cusor(page)
     ///
     /// This doesn't affect page itself but change cursor.
@@ -96,6 +252,7 @@ impl Processor {
     /// * has_header : Whether csv data has header or not.
     /// * line_ending : Optional line_ending configuration.
     /// * raw_mode : This decides whether page be data or array
+    /// * delimiter : Optional field delimiter. Default is comma
     pub fn add_page(
         &mut self,
         page: &str,
@@ -103,6 +260,7 @@ impl Processor {
         has_header: bool,
         line_ending: Option<char>,
         raw_mode: bool,
+        delimiter: Option<char>,
     ) -> CedResult<()> {
         if self.pages.contains_key(page) {
             return Err(CedError::InvalidPageOperation(format!(
@@ -110,33 +268,402 @@ impl Processor {
                 page
             )));
         } else {
-            let mut ignore_empty_row = true;
-            if let Ok(val) = std::env::var("CED_READ_STRICT") {
-                if val.to_lowercase() == "true" {
-                    ignore_empty_row = false;
-                }
-            }
-            let mut reader = dcsv::Reader::new()
-                .use_line_delimiter(line_ending.unwrap_or('\n'))
-                .has_header(has_header)
-                .ignore_empty_row(ignore_empty_row);
-
-            let page_data = if raw_mode {
-                Page::new_array(reader.array_from_stream(data.as_bytes())?)
-            } else {
-                Page::new_data(reader.data_from_stream(data.as_bytes())?)
-            };
+            let page_data = read_page(data, has_header, line_ending, raw_mode, delimiter)?;
             self.pages.insert(page.to_owned(), page_data);
             self.cursor = Some(page.to_owned());
             Ok(())
         }
     }
 
+    /// Append rows from a csv file to the end of a page
+    ///
+    /// Column names of file should match page's columns. If file has no header, only column
+    /// count is compared.
+    ///
+    /// # Return
+    ///
+    /// Count of appended rows
+    pub fn append_from_file(
+        &mut self,
+        page: &str,
+        path: impl AsRef<Path>,
+        has_header: bool,
+    ) -> CedResult<usize> {
+        let content = std::fs::read_to_string(&path).map_err(|err| {
+            CedError::io_error(
+                err,
+                &format!("Failed to append file \"{}\"", path.as_ref().display()),
+            )
+        })?;
+        let src_page = read_page(&content, has_header, None, false, None)?;
+        let dest_page = self.get_page_data(page)?;
+        let src_names = src_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<Vec<_>>();
+        let dest_names = dest_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<Vec<_>>();
+        let is_matching = if has_header {
+            src_names == dest_names
+        } else {
+            src_names.len() == dest_names.len()
+        };
+        if !is_matching {
+            return Err(CedError::InvalidRowData(format!(
+                "Columns don't match. Expected [{}] but got [{}]",
+                dest_names.join(","),
+                src_names.join(",")
+            )));
+        }
+
+        // Convert appended values into page's column types
+        let mut rows = vec![];
+        for row in src_page.rows_iter() {
+            let values = row
+                .iter()
+                .zip(dest_page.get_columns())
+                .map(|(value, column)| Value::from_str(&value.to_string(), column.column_type))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push(values);
+        }
+
+        let dest_page = self.get_page_data_mut(page)?;
+        let count = rows.len();
+        for values in rows {
+            dest_page.insert_row(dest_page.get_row_count(), Some(&values))?;
+        }
+        Ok(count)
+    }
+
     /// Remove page with given name
     ///
-    /// This doesn't panic and silent do nothing if page name is non-existent
-    pub fn remove_page(&mut self, page_name: &str) {
-        self.pages.remove_entry(page_name);
+    /// This doesn't panic and silent do nothing if page name is non-existent. If removed page
+    /// was a cursor, cursor moves to another page or becomes none.
+    ///
+    /// # Return
+    ///
+    /// Whether the page existed and was removed
+    pub fn remove_page(&mut self, page_name: &str) -> bool {
+        let removed = self.pages.remove_entry(page_name).is_some();
+        if self.cursor.as_deref() == Some(page_name) {
+            self.cursor = self.get_page_names().into_iter().next();
+        }
+        removed
+    }
+
+    /// Get names of all pages sorted
+    pub fn get_page_names(&self) -> Vec<String> {
+        let mut names = self.pages.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Get count of pages
+    pub fn get_page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Get information of all pages sorted by name
+    pub fn list_pages(&self) -> Vec<PageInfo> {
+        self.get_page_names()
+            .into_iter()
+            .map(|name| self.get_page_info(&name, &self.pages[&name]))
+            .collect()
+    }
+
+    /// Iterate pages with their information
+    ///
+    /// Iteration order is arbitrary. Use list_pages for sorted information.
+    pub fn iter_pages(&self) -> impl Iterator<Item = (&str, PageInfo)> + '_ {
+        self.pages
+            .iter()
+            .map(|(name, page)| (name.as_str(), self.get_page_info(name, page)))
+    }
+
+    fn get_page_info(&self, name: &str, page: &Page) -> PageInfo {
+        PageInfo {
+            name: name.to_owned(),
+            rows: page.get_row_count(),
+            columns: page.get_column_count(),
+            is_array: page.is_array(),
+            source_file: page
+                .source_file
+                .as_ref()
+                .map(|file| file.display().to_string()),
+            is_cursor: self.cursor.as_deref() == Some(name),
+        }
+    }
+
+    /// Rename a page
+    ///
+    /// Cursor follows the renamed page. Source file of the page is kept as it is.
+    pub fn rename_page(&mut self, old: &str, new_name: &str) -> CedResult<()> {
+        if self.pages.contains_key(new_name) {
+            return Err(CedError::InvalidPageOperation(format!(
+                "\"{}\" already exists",
+                new_name
+            )));
+        }
+        let page = self.pages.remove(old).ok_or_else(|| {
+            CedError::InvalidPageOperation(format!("Page \"{}\" doesn't exist", old))
+        })?;
+        self.pages.insert(new_name.to_owned(), page);
+        if self.cursor.as_deref() == Some(old) {
+            self.cursor = Some(new_name.to_owned());
+        }
+        Ok(())
+    }
+
+    /// Copy a page under a new name
+    ///
+    /// Copied page doesn't have a source file, thus it cannot be written without export.
+    pub fn copy_page(&mut self, src: &str, new_name: &str) -> CedResult<()> {
+        if self.pages.contains_key(new_name) {
+            return Err(CedError::InvalidPageOperation(format!(
+                "\"{}\" already exists",
+                new_name
+            )));
+        }
+        let mut page = self.get_page_data(src)?.clone();
+        page.source_file = None;
+        self.pages.insert(new_name.to_owned(), page);
+        Ok(())
+    }
+
+    /// Append rows of a source page to a destination page
+    ///
+    /// Values are matched by column name. In strict mode, both pages should have same column
+    /// names. Otherwise missing columns are filled with destination's default values and extra
+    /// columns of source page are ignored.
+    ///
+    /// # Return
+    ///
+    /// Count of appended rows
+    pub fn merge_pages(&mut self, src: &str, dest: &str, strict: bool) -> CedResult<usize> {
+        let src_page = self.get_page_data(src)?;
+        let dest_page = self.get_page_data(dest)?;
+        let src_names = src_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<HashSet<_>>();
+        let dest_names = dest_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<HashSet<_>>();
+        if strict && src_names != dest_names {
+            return Err(CedError::InvalidPageOperation(format!(
+                "Columns of \"{}\" and \"{}\" don't match",
+                src, dest
+            )));
+        }
+
+        // Convert source rows into destination's column order and types
+        let mut rows = vec![];
+        for row_index in 0..src_page.get_row_count() {
+            let mut values = vec![];
+            for column in dest_page.get_columns() {
+                let value = match src_page.try_get_column_index(&column.name) {
+                    Some(column_index) => Value::from_str(
+                        &src_page
+                            .get_cell(row_index, column_index)
+                            .map(|value| value.to_string())
+                            .unwrap_or_default(),
+                        column.column_type,
+                    )?,
+                    None => column.get_default_value(),
+                };
+                values.push(value);
+            }
+            rows.push(values);
+        }
+
+        let dest_page = self.get_page_data_mut(dest)?;
+        let count = rows.len();
+        for values in rows {
+            dest_page.insert_row(dest_page.get_row_count(), Some(&values))?;
+        }
+        Ok(count)
+    }
+
+    /// Left join two pages on a key column into a new page
+    ///
+    /// For each left row, first right row with same key value is joined. Right page's non-key
+    /// columns are appended after left columns. Unmatched rows get empty values, or default
+    /// values for number columns.
+    ///
+    /// # Return
+    ///
+    /// Row count of output page
+    pub fn left_join(
+        &mut self,
+        left: &str,
+        right: &str,
+        key: &str,
+        output: &str,
+    ) -> CedResult<usize> {
+        if self.pages.contains_key(output) {
+            return Err(CedError::InvalidPageOperation(format!(
+                "\"{}\" already exists",
+                output
+            )));
+        }
+        let left_page = self.get_page_data(left)?;
+        let right_page = self.get_page_data(right)?;
+        if left_page.is_array() || right_page.is_array() {
+            return Err(CedError::InvalidPageOperation(
+                "Cannot join virtual array".to_string(),
+            ));
+        }
+        let key_error = |page: &str| {
+            CedError::InvalidColumn(format!("Column : \"{}\" is not valid in \"{}\"", key, page))
+        };
+        let left_key = left_page
+            .try_get_column_index(key)
+            .ok_or_else(|| key_error(left))?;
+        let right_key = right_page
+            .try_get_column_index(key)
+            .ok_or_else(|| key_error(right))?;
+
+        let right_columns = right_page
+            .get_columns()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != right_key)
+            .collect::<Vec<_>>();
+        let mut joined = Page::new_data(VirtualData::new());
+        for (index, column) in left_page
+            .get_columns()
+            .iter()
+            .chain(right_columns.iter().map(|(_, column)| *column))
+            .enumerate()
+        {
+            if joined.try_get_column_index(&column.name).is_some() {
+                return Err(CedError::InvalidColumn(format!(
+                    "Column : \"{}\" exists in both pages",
+                    column.name
+                )));
+            }
+            joined.insert_column_with_type(index, &column.name, column.column_type, None, None)?;
+        }
+
+        let right_rows = right_page.get_rows();
+        for (row_index, values) in left_page.rows_iter().enumerate() {
+            let key_value = values[left_key].to_string();
+            let matched = right_rows
+                .iter()
+                .find(|row| row[right_key].to_string() == key_value);
+            let mut values = values.into_iter().cloned().collect::<Vec<_>>();
+            for (index, column) in &right_columns {
+                values.push(match matched {
+                    Some(row) => row[*index].clone(),
+                    None if column.column_type == ValueType::Text => Value::Text(String::new()),
+                    None => column.get_default_value(),
+                });
+            }
+            joined.insert_row(row_index, Some(&values))?;
+        }
+
+        let count = joined.get_row_count();
+        self.pages.insert(output.to_owned(), joined);
+        Ok(count)
+    }
+
+    /// Get differences from page "a" to page "b"
+    ///
+    /// Rows are compared by index. Both pages should have same column names.
+    pub fn diff_pages(&self, a: &str, b: &str) -> CedResult<PageDiff> {
+        let old_page = self.get_page_data(a)?;
+        let new_page = self.get_page_data(b)?;
+        let old_names = old_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<Vec<_>>();
+        let new_names = new_page
+            .get_columns()
+            .iter()
+            .map(|col| col.name.as_str())
+            .collect::<Vec<_>>();
+        if old_names != new_names {
+            return Err(CedError::InvalidPageOperation(format!(
+                "Columns of \"{}\" and \"{}\" don't match",
+                a, b
+            )));
+        }
+
+        let old_count = old_page.get_row_count();
+        let new_count = new_page.get_row_count();
+        let mut diff = PageDiff {
+            added: (old_count..new_count).collect(),
+            removed: (new_count..old_count).collect(),
+            changed: vec![],
+        };
+        for (row_index, (old_row, new_row)) in
+            old_page.rows_iter().zip(new_page.rows_iter()).enumerate()
+        {
+            for (column_index, (old, new)) in old_row.iter().zip(new_row).enumerate() {
+                if old.to_string() != new.to_string() {
+                    diff.changed
+                        .push((row_index, column_index, (*old).clone(), new.clone()));
+                }
+            }
+        }
+        Ok(diff)
+    }
+
+    /// Save a page's content as a named snapshot
+    ///
+    /// Existing snapshot with same name is overwritten
+    pub fn save_snapshot(&mut self, page: &str, name: &str) -> CedResult<()> {
+        let page = self.get_page_data(page)?.clone();
+        self.snapshots.insert(name.to_owned(), page);
+        Ok(())
+    }
+
+    /// Restore a page's content from a named snapshot
+    ///
+    /// Source file of the page is kept as it is
+    pub fn restore_snapshot(&mut self, page: &str, name: &str) -> CedResult<()> {
+        let mut snapshot = self
+            .snapshots
+            .get(name)
+            .ok_or_else(|| {
+                CedError::InvalidPageOperation(format!("Snapshot \"{}\" doesn't exist", name))
+            })?
+            .clone();
+        let page = self.get_page_data_mut(page)?;
+        snapshot.source_file = page.source_file.take();
+        *page = snapshot;
+        Ok(())
+    }
+
+    /// Get information of all snapshots sorted by name
+    pub fn list_snapshots(&self) -> Vec<PageInfo> {
+        let mut names = self.snapshots.keys().collect::<Vec<_>>();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                let snapshot = &self.snapshots[name];
+                PageInfo {
+                    name: name.to_owned(),
+                    rows: snapshot.get_row_count(),
+                    columns: snapshot.get_column_count(),
+                    is_array: snapshot.is_array(),
+                    source_file: snapshot
+                        .source_file
+                        .as_ref()
+                        .map(|file| file.display().to_string()),
+                    is_cursor: false,
+                }
+            })
+            .collect()
     }
 
     /// Check if processor contains a page
@@ -176,30 +703,52 @@ impl Processor {
     }
 
     /// Drop all data from processor
+    ///
+    /// This clears every page and the cursor.
     pub fn drop_pages(&mut self) -> CedResult<()> {
         self.pages.clear();
         self.cursor = None;
         Ok(())
     }
 
+    /// Check if any page has content which is not written to its source file
+    ///
+    /// A page without a source file is considered unsaved if it has any row.
+    #[cfg(feature = "cli")]
+    pub(crate) fn has_unsaved_pages(&self) -> bool {
+        self.pages
+            .values()
+            .any(|page| match page.source_file.as_ref() {
+                Some(file) => std::fs::read_to_string(file)
+                    .map(|content| content != page.to_string())
+                    .unwrap_or(true),
+                None => page.get_row_count() != 0,
+            })
+    }
+
     /// Import file content as page
     ///
     /// This will drop the page if given page name already exists.
     ///
+    /// With "gz" feature, file with ".gz" extension is decompressed before import. Such page has
+    /// no source file, because overwrite would write uncompressed content.
+    ///
     /// # Args
     ///
     /// * path: File path to import from
     /// * has_header : Whether csv file has header or not
     /// * line_ending : Optional line_ending of csv
     /// * raw_mode : Whether imported as data or array
+    /// * delimiter : Optional field delimiter of csv. Default is comma
     pub fn import_from_file(
         &mut self,
         path: impl AsRef<Path>,
         has_header: bool,
         line_ending: Option<char>,
         raw_mode: bool,
+        delimiter: Option<char>,
     ) -> CedResult<()> {
-        let content = std::fs::read_to_string(&path).map_err(|err| {
+        let file = File::open(&path).map_err(|err| {
             CedError::io_error(
                 err,
                 &format!("Failed to import file \"{}\"", path.as_ref().display()),
@@ -207,7 +756,31 @@ impl Processor {
         })?;
         let page_name = &path.as_ref().display().to_string();
 
-        self.add_page(page_name, &content, has_header, line_ending, raw_mode)?;
+        #[cfg(feature = "gz")]
+        if path
+            .as_ref()
+            .extension()
+            .map(|ext| ext == "gz")
+            .unwrap_or(false)
+        {
+            return self.import_from_reader(
+                flate2::read::GzDecoder::new(file),
+                page_name,
+                has_header,
+                line_ending,
+                raw_mode,
+                delimiter,
+            );
+        }
+
+        self.import_from_reader(
+            file,
+            page_name,
+            has_header,
+            line_ending,
+            raw_mode,
+            delimiter,
+        )?;
 
         // Set source file because it was imported from file
         self.pages
@@ -217,20 +790,278 @@ impl Processor {
         Ok(())
     }
 
+    /// Import csv string as a page
+    ///
+    /// This is same with add_page with default line ending and data mode. Processor's default
+    /// delimiter is used. Fails if given page name already exists.
+    pub fn import_from_str(
+        &mut self,
+        data: &str,
+        page_name: &str,
+        has_header: bool,
+    ) -> CedResult<()> {
+        self.add_page(page_name, data, has_header, None, false, self.delimiter)
+    }
+
+    /// Reload a page from its source file
+    ///
+    /// Masked columns are kept but limiters are discarded because content is read again. This
+    /// fails if page has no source file.
+    pub fn reload_from_file(&mut self, page: &str) -> CedResult<()> {
+        let page_data = self.get_page_data(page)?;
+        let raw_mode = page_data.is_array();
+        let source = page_data.source_file.clone().ok_or_else(|| {
+            CedError::InvalidPageOperation(format!("Page \"{}\" has no source file", page))
+        })?;
+        let content = std::fs::read_to_string(&source).map_err(|err| {
+            CedError::io_error(
+                err,
+                &format!("Failed to reload file \"{}\"", source.display()),
+            )
+        })?;
+        let mut reloaded = read_page(&content, true, None, raw_mode, self.delimiter)?;
+        let page = self.get_page_data_mut(page)?;
+        reloaded.source_file = page.source_file.take();
+        reloaded.masked_columns = std::mem::take(&mut page.masked_columns);
+        reloaded.ranges = std::mem::take(&mut page.ranges);
+        *page = reloaded;
+        Ok(())
+    }
+
+    /// Import content from a reader as page
+    ///
+    /// This reads reader until EOF. Fails if given page name already exists.
+    ///
+    /// # Args
+    ///
+    /// * reader: Source to read csv content from
+    /// * page_name: Name of page to create
+    /// * has_header : Whether csv content has header or not
+    /// * line_ending : Optional line_ending of csv
+    /// * raw_mode : Whether imported as data or array
+    /// * delimiter : Optional field delimiter of csv. Default is comma
+    pub fn import_from_reader<R: Read>(
+        &mut self,
+        mut reader: R,
+        page_name: &str,
+        has_header: bool,
+        line_ending: Option<char>,
+        raw_mode: bool,
+        delimiter: Option<char>,
+    ) -> CedResult<()> {
+        let mut content = String::new();
+        reader
+            .read_to_string(&mut content)
+            .map_err(|err| CedError::io_error(err, "Failed to read csv content from source"))?;
+        self.add_page(
+            page_name,
+            &content,
+            has_header,
+            line_ending,
+            raw_mode,
+            delimiter,
+        )
+    }
+
+    /// Import csv content from stdin into a page named "stdin"
+    ///
+    /// This reads stdin until EOF. Existing "stdin" page is replaced only if a new content is read
+    /// successfully. Processor's default delimiter is used.
+    pub fn import_from_stdin(&mut self, has_header: bool, raw_mode: bool) -> CedResult<()> {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|err| CedError::io_error(err, "Failed to read csv content from stdin"))?;
+        let page_data = read_page(&content, has_header, None, raw_mode, self.delimiter)?;
+        self.remove_page(STDIN_PAGE);
+        self.pages.insert(STDIN_PAGE.to_owned(), page_data);
+        self.cursor = Some(STDIN_PAGE.to_owned());
+        Ok(())
+    }
+
     /// Write all page's content into a file
-    pub fn write_to_file(&self, page: &str, file: impl AsRef<Path>) -> CedResult<()> {
+    ///
+    /// * delimiter : Optional field delimiter. Default is comma
+    pub fn write_to_file(
+        &self,
+        page: &str,
+        file: impl AsRef<Path>,
+        delimiter: Option<char>,
+    ) -> CedResult<()> {
         let mut file = File::create(file)
             .map_err(|err| CedError::io_error(err, "Failed to open file for write"))?;
+        self.export_to_writer(&mut file, page, delimiter)
+    }
+
+    /// Write all page's content into a gzip compressed file
+    #[cfg(feature = "gz")]
+    pub fn write_to_file_gz(&self, page: &str, file: impl AsRef<Path>) -> CedResult<()> {
+        let file = File::create(file)
+            .map_err(|err| CedError::io_error(err, "Failed to open file for write"))?;
+        let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        self.export_to_writer(&mut encoder, page, None)?;
+        encoder
+            .finish()
+            .map_err(|err| CedError::io_error(err, "Failed to compress csv content to file"))?;
+        Ok(())
+    }
 
-        file.write_all(self.get_page_as_string(page)?.as_bytes())
-            .map_err(|err| CedError::io_error(err, "Failed to write csv content to file"))?;
+    /// Write page's content to stdout as plain csv
+    ///
+    /// Masked columns are not included
+    pub fn export_to_stdout(&self, page: &str) -> CedResult<()> {
+        utils::write_to_stdout(&self.get_page_as_string(page)?)
+    }
+
+    /// Write all page's content into a writer
+    ///
+    /// * delimiter : Optional field delimiter. Default is comma
+    pub fn export_to_writer<W: Write>(
+        &self,
+        writer: &mut W,
+        page: &str,
+        delimiter: Option<char>,
+    ) -> CedResult<()> {
+        let csv = self
+            .get_page_data(page)?
+            .to_string_with_delimiter(delimiter);
+        writer
+            .write_all(csv.as_bytes())
+            .map_err(|err| CedError::io_error(err, "Failed to write csv content to writer"))?;
         Ok(())
     }
 
+    /// Get page's content as a json array of objects
+    ///
+    /// Each object is keyed by column name. Numbers are written as json numbers and empty cells
+    /// as null.
+    pub fn export_as_json(&self, page: &str) -> CedResult<String> {
+        self.get_json_with_rows(page, None)
+    }
+
+    fn get_json_with_rows(&self, page: &str, rows: Option<&[usize]>) -> CedResult<String> {
+        let objects = self
+            .get_json_objects(page, rows)?
+            .iter()
+            .map(|object| format!("  {}", object))
+            .collect::<Vec<_>>();
+        if objects.is_empty() {
+            return Ok("[]\n".to_string());
+        }
+        Ok(format!("[\n{}\n]\n", objects.join(",\n")))
+    }
+
+    /// Get page's content as json lines
+    ///
+    /// Each line is a json object of a row. Values are serialized same as export_as_json.
+    pub fn export_as_jsonl(&self, page: &str) -> CedResult<String> {
+        self.get_jsonl_with_rows(page, None)
+    }
+
+    fn get_jsonl_with_rows(&self, page: &str, rows: Option<&[usize]>) -> CedResult<String> {
+        Ok(self
+            .get_json_objects(page, rows)?
+            .iter()
+            .map(|object| format!("{}\n", object))
+            .collect())
+    }
+
+    /// Get page's content in a given output format
+    ///
+    /// Only given rows are included if rows are supplied. Table format is same with csv here,
+    /// because numbered table is only printed by command.
+    pub fn get_page_as_format(
+        &self,
+        page: &str,
+        format: OutputFormat,
+        rows: Option<&[usize]>,
+    ) -> CedResult<String> {
+        let formatted = match format {
+            OutputFormat::Csv | OutputFormat::Table => {
+                self.get_page_data(page)?.to_string_with_rows(',', rows)
+            }
+            OutputFormat::Tsv => self.get_page_data(page)?.to_string_with_rows('\t', rows),
+            OutputFormat::Json => self.get_json_with_rows(page, rows)?,
+            OutputFormat::Jsonl => self.get_jsonl_with_rows(page, rows)?,
+        };
+        Ok(formatted)
+    }
+
+    /// Set output format of print command
+    pub fn set_output_format(&mut self, format: OutputFormat) {
+        self.output_format = format;
+    }
+
+    /// Set a variable which substitutes "$name" argument of a command
+    ///
+    /// Name should start with an alphabet and consist of alphanumerics and underscores
+    pub fn set_variable(&mut self, name: &str, value: &str) -> CedResult<()> {
+        if !utils::is_variable_name(name) {
+            return Err(CedError::CommandError(format!(
+                "\"{}\" is not a valid variable name",
+                name
+            )));
+        }
+        self.variables.insert(name.to_owned(), value.to_owned());
+        Ok(())
+    }
+
+    /// Get value of a variable
+    pub fn get_variable(&self, name: &str) -> Option<&str> {
+        self.variables.get(name).map(|value| value.as_str())
+    }
+
+    /// Set default field delimiter of import, export and write commands
+    ///
+    /// Delimiter given as a command argument takes precedence. None means comma.
+    pub fn set_delimiter(&mut self, delimiter: Option<char>) {
+        self.delimiter = delimiter;
+    }
+
+    /// Get each row as a json object string
+    ///
+    /// Only given rows are included if rows are supplied
+    fn get_json_objects(&self, page: &str, rows: Option<&[usize]>) -> CedResult<Vec<String>> {
+        let page = self.get_page_data(page)?;
+        let keys = page
+            .get_columns()
+            .iter()
+            .map(|col| utils::escape_json(&col.name))
+            .collect::<Vec<_>>();
+        let objects = page
+            .rows_iter()
+            .enumerate()
+            .filter(|(index, _)| rows.map(|rows| rows.contains(index)).unwrap_or(true))
+            .map(|(_, row)| {
+                let fields = row
+                    .iter()
+                    .zip(keys.iter())
+                    .map(|(value, key)| {
+                        let value = match value {
+                            Value::Number(num) => num.to_string(),
+                            Value::Text(text) if text.is_empty() => "null".to_string(),
+                            Value::Text(text) => utils::escape_json(text),
+                        };
+                        format!("{}:{}", key, value)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("{{{}}}", fields)
+            })
+            .collect::<Vec<_>>();
+        Ok(objects)
+    }
+
     /// Overwrite virtual data's content into a imported file
     ///
     /// * cache : whether to backup original file's content into temp directory
-    pub fn overwrite_to_file(&self, page: &str, cache: bool) -> CedResult<bool> {
+    /// * delimiter : Optional field delimiter. Default is comma
+    pub fn overwrite_to_file(
+        &self,
+        page: &str,
+        cache: bool,
+        delimiter: Option<char>,
+    ) -> CedResult<bool> {
         let page = self.get_page_data(page)?;
         let file = page.source_file.as_ref();
         if file.is_none() {
@@ -238,7 +1069,7 @@ impl Processor {
         }
 
         let file = file.unwrap();
-        let csv = page.to_string();
+        let csv = page.to_string_with_delimiter(delimiter);
         // Cache file into temp directory
         if cache {
             std::fs::copy(file, std::env::temp_dir().join("cache.csv"))
@@ -256,6 +1087,41 @@ impl Processor {
         Ok(())
     }
 
+    /// Edit a cell only if a value qualifies the column's type, limiter and range
+    ///
+    /// # Return
+    ///
+    /// Whether the cell was edited. This fails only when page doesn't exist or coordinate is out
+    /// of range.
+    pub fn set_cell_if_valid(
+        &mut self,
+        page: &str,
+        x: usize,
+        y: usize,
+        input: &str,
+    ) -> CedResult<bool> {
+        let page = self.get_page_data_mut(page)?;
+        if x >= page.get_row_count() || y >= page.get_column_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        // Array has no limiter
+        if !page.is_array() {
+            let column = &page.get_columns()[y];
+            let range = page.ranges.get(&column.name);
+            let valid = match utils::column_value(column, input) {
+                Ok(value) => {
+                    check_value(&value, column.column_type, &column.limiter, range).is_none()
+                }
+                Err(_) => false,
+            };
+            if !valid {
+                return Ok(false);
+            }
+        }
+        page.set_cell_from_string(x, y, input)?;
+        Ok(true)
+    }
+
     /// Edit a column by given coordinate
     ///
     /// This overwrite all column values with given input
@@ -265,6 +1131,465 @@ impl Processor {
         Ok(())
     }
 
+    /// Edit cells of a column only in rows where another column matches a regex pattern
+    ///
+    /// New value should qualify edit column's limiter. Nothing is edited if it doesn't.
+    ///
+    /// # Return
+    ///
+    /// Count of edited cells
+    pub fn edit_cells_matching(
+        &mut self,
+        page: &str,
+        search_column: &str,
+        pattern: &str,
+        edit_column: &str,
+        new_value: &str,
+    ) -> CedResult<usize> {
+        let rows = self.find_matching_rows(page, search_column, pattern)?;
+        let column_index = self.get_column_index(page, edit_column)?;
+        let page = self.get_page_data_mut(page)?;
+        for &row_index in &rows {
+            page.set_cell_from_string(row_index, column_index, new_value)?;
+        }
+        Ok(rows.len())
+    }
+
+    /// Find and replace all occurrences of a pattern across every column
+    ///
+    /// This is same with find_replace without a column
+    pub fn find_and_replace_all(
+        &mut self,
+        page: &str,
+        pattern: &str,
+        replacement: &str,
+    ) -> CedResult<usize> {
+        self.find_replace(page, None, pattern, replacement)
+    }
+
+    /// Find and replace occurrences of a pattern in a column
+    ///
+    /// Every text column is searched if column is none. Replacement can refer capture groups
+    /// such as "$1". Substitution is only applied to text values. A cell which fails its
+    /// column limiter after substitution is skipped with a warning.
+    ///
+    /// # Return
+    ///
+    /// Total count of substitutions made
+    pub fn find_replace(
+        &mut self,
+        page: &str,
+        column: Option<&str>,
+        pattern: &str,
+        replacement: &str,
+    ) -> CedResult<usize> {
+        let (occurrences, _) = self.replace_in_cells(page, column, pattern, replacement)?;
+        Ok(occurrences)
+    }
+
+    /// Substitute a regex pattern in every cell of a column
+    ///
+    /// This is same with find_replace but counts changed cells instead of occurrences.
+    ///
+    /// # Return
+    ///
+    /// Count of changed cells
+    pub fn edit_column_with_regex(
+        &mut self,
+        page: &str,
+        column: &str,
+        pattern: &str,
+        replacement: &str,
+    ) -> CedResult<usize> {
+        let (_, cells) = self.replace_in_cells(page, Some(column), pattern, replacement)?;
+        Ok(cells)
+    }
+
+    /// Replace a regex pattern in cells
+    ///
+    /// # Return
+    ///
+    /// Count of substitutions and count of changed cells
+    fn replace_in_cells(
+        &mut self,
+        page: &str,
+        column: Option<&str>,
+        pattern: &str,
+        replacement: &str,
+    ) -> CedResult<(usize, usize)> {
+        let regex = utils::build_regex(pattern)?;
+        let page = self.get_page_data_mut(page)?;
+        let columns = match column {
+            Some(column) => {
+                let column_index = page.try_get_column_index(column).ok_or_else(|| {
+                    CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+                })?;
+                column_index..column_index + 1
+            }
+            None => 0..page.get_column_count(),
+        };
+        let mut count = 0;
+        let mut cells = 0;
+        for row_index in 0..page.get_row_count() {
+            for column_index in columns.clone() {
+                let (source, match_count) = match page.get_cell(row_index, column_index) {
+                    Some(Value::Text(text)) => (text.to_owned(), regex.find_iter(text).count()),
+                    _ => continue,
+                };
+                if match_count == 0 {
+                    continue;
+                }
+                let replaced = regex.replace_all(&source, replacement);
+                if let Err(err) = page.set_cell_from_string(row_index, column_index, &replaced) {
+                    utils::write_to_stderr(&format!(
+                        "WRN : Skipped cell \"({},{})\" =\n{}\n",
+                        row_index, column_index, err
+                    ))?;
+                    continue;
+                }
+                count += match_count;
+                cells += 1;
+            }
+        }
+        Ok((count, cells))
+    }
+
+    /// Find indices of rows whose column value matches a regex pattern
+    pub fn find_matching_rows(
+        &self,
+        page: &str,
+        column: &str,
+        pattern: &str,
+    ) -> CedResult<Vec<usize>> {
+        let regex = utils::build_regex(pattern)?;
+        let column_index = self.get_column_index(page, column)?;
+        let page = self.get_page_data(page)?;
+        let matched = (0..page.get_row_count())
+            .filter(|&row_index| {
+                page.get_cell(row_index, column_index)
+                    .map(|value| regex.is_match(&value.to_string()))
+                    .unwrap_or(false)
+            })
+            .collect();
+        Ok(matched)
+    }
+
+    /// Count rows of a page
+    ///
+    /// Without column every row is counted. Without value, non-empty cells of the column are
+    /// counted. Otherwise cells equal to value are counted, or cells matching value as a regex
+    /// pattern when use_regex is true.
+    pub fn count_matching(
+        &self,
+        page: &str,
+        column: Option<&str>,
+        value: Option<&str>,
+        use_regex: bool,
+    ) -> CedResult<usize> {
+        let column = match column {
+            Some(column) => column,
+            None => return self.get_row_count(page),
+        };
+        let values = self.get_column_values(page, column)?;
+        let count = match value {
+            None => values
+                .iter()
+                .filter(|value| !value.to_string().is_empty())
+                .count(),
+            Some(pattern) if use_regex => {
+                let regex = utils::build_regex(pattern)?;
+                values
+                    .iter()
+                    .filter(|value| regex.is_match(&value.to_string()))
+                    .count()
+            }
+            Some(target) => values
+                .iter()
+                .filter(|value| value.to_string() == target)
+                .count(),
+        };
+        Ok(count)
+    }
+
+    /// Find rows which contain a query
+    ///
+    /// Every column is searched if column is not given. Query is matched as a substring unless
+    /// use_regex is true.
+    pub fn find_rows(
+        &self,
+        page: &str,
+        column: Option<&str>,
+        query: &str,
+        use_regex: bool,
+    ) -> CedResult<Vec<usize>> {
+        let regex = if use_regex {
+            Some(utils::build_regex(query)?)
+        } else {
+            None
+        };
+        let page = self.get_page_data(page)?;
+        let column_index = match column {
+            Some(column) => Some(page.try_get_column_index(column).ok_or_else(|| {
+                CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+            })?),
+            None => None,
+        };
+        let is_match = |value: &Value| {
+            let value = value.to_string();
+            match &regex {
+                Some(regex) => regex.is_match(&value),
+                None => value.contains(query),
+            }
+        };
+        let found = page
+            .rows_iter()
+            .enumerate()
+            .filter(|(_, row)| match column_index {
+                Some(index) => row.get(index).map(|value| is_match(value)).unwrap_or(false),
+                None => row.iter().any(|value| is_match(value)),
+            })
+            .map(|(row_index, _)| row_index)
+            .collect();
+        Ok(found)
+    }
+
+    /// Filter rows by a regex pattern
+    ///
+    /// When remove_non_matching is true, rows that don't match are removed from the page.
+    ///
+    /// # Return
+    ///
+    /// Indices of matched rows before any removal
+    pub fn filter_rows(
+        &mut self,
+        page: &str,
+        column: &str,
+        pattern: &str,
+        remove_non_matching: bool,
+    ) -> CedResult<Vec<usize>> {
+        let matched = self.find_matching_rows(page, column, pattern)?;
+        if remove_non_matching {
+            self.retain_rows(page, &matched)?;
+        }
+        Ok(matched)
+    }
+
+    /// Remove every row except given rows
+    ///
+    /// Given rows should be sorted
+    ///
+    /// # Return
+    ///
+    /// Count of removed rows
+    pub fn retain_rows(&mut self, page: &str, rows: &[usize]) -> CedResult<usize> {
+        let page = self.get_page_data_mut(page)?;
+        let mut count = 0;
+        // Delete from behind so that indices are not shifted
+        for row_index in (0..page.get_row_count()).rev() {
+            if rows.binary_search(&row_index).is_err() {
+                page.delete_row(row_index);
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Select random rows uniformly
+    ///
+    /// Same seed gives same rows. Every row is selected if count is bigger than row count.
+    ///
+    /// # Return
+    ///
+    /// Sorted indices of selected rows
+    pub fn sample_rows(
+        &self,
+        page: &str,
+        count: usize,
+        seed: Option<u64>,
+    ) -> CedResult<Vec<usize>> {
+        let row_count = self.get_row_count(page)?;
+        let count = count.min(row_count);
+        let mut rng = utils::Rng::new(seed);
+        let mut rows = (0..row_count).collect::<Vec<_>>();
+        // Partial fisher-yates shuffle
+        for index in 0..count {
+            let target = index + rng.below(row_count - index);
+            rows.swap(index, target);
+        }
+        rows.truncate(count);
+        rows.sort_unstable();
+        Ok(rows)
+    }
+
+    /// Fill empty cells with a non-empty value above them
+    ///
+    /// Every column is filled if column is none. A cell which fails its column limiter is skipped
+    /// with a warning.
+    ///
+    /// # Return
+    ///
+    /// Count of filled cells
+    pub fn fill_down(&mut self, page: &str, column: Option<&str>) -> CedResult<usize> {
+        self.fill_empty_cells(page, column, false)
+    }
+
+    /// Fill empty cells with a non-empty value below them
+    ///
+    /// This is a reversed version of fill_down.
+    pub fn fill_up(&mut self, page: &str, column: Option<&str>) -> CedResult<usize> {
+        self.fill_empty_cells(page, column, true)
+    }
+
+    /// Apply a transform to every cell of a column
+    ///
+    /// Cell is only set when transformed value differs. A cell which fails its column limiter is
+    /// skipped and its error is collected. Error from the transform stops the operation.
+    ///
+    /// # Return
+    ///
+    /// Count of modified cells and errors of skipped cells with their row indices
+    pub fn apply_to_column<F: Fn(&Value) -> CedResult<Value>>(
+        &mut self,
+        page: &str,
+        column: &str,
+        f: F,
+    ) -> CedResult<(usize, Vec<(usize, CedError)>)> {
+        let page = self.get_page_data_mut(page)?;
+        let column_index = page.try_get_column_index(column).ok_or_else(|| {
+            CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+        })?;
+        let mut count = 0;
+        let mut skipped = vec![];
+        for row_index in 0..page.get_row_count() {
+            let source = match page.get_cell(row_index, column_index) {
+                Some(value) => value,
+                None => continue,
+            };
+            let value = f(source)?;
+            if value.get_type() == source.get_type() && value.to_string() == source.to_string() {
+                continue;
+            }
+            if let Err(err) = page.set_cell(row_index, column_index, value) {
+                skipped.push((row_index, err));
+                continue;
+            }
+            count += 1;
+        }
+        Ok((count, skipped))
+    }
+
+    /// Trim leading and trailing whitespaces of text cells
+    ///
+    /// Every column is normalized if column is none. Number values are skipped.
+    ///
+    /// # Return
+    ///
+    /// Count of modified cells
+    pub fn normalize_whitespace(&mut self, page: &str, column: Option<&str>) -> CedResult<usize> {
+        self.normalize(page, column, None)
+    }
+
+    /// Trim text cells and optionally convert their case
+    ///
+    /// A cell which fails its column limiter after normalization is skipped with a warning.
+    ///
+    /// # Return
+    ///
+    /// Count of modified cells
+    pub fn normalize(
+        &mut self,
+        page: &str,
+        column: Option<&str>,
+        case: Option<TextCase>,
+    ) -> CedResult<usize> {
+        let page = self.get_page_data_mut(page)?;
+        let columns = match column {
+            Some(column) => {
+                let column_index = page.try_get_column_index(column).ok_or_else(|| {
+                    CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+                })?;
+                column_index..column_index + 1
+            }
+            None => 0..page.get_column_count(),
+        };
+        let mut count = 0;
+        for row_index in 0..page.get_row_count() {
+            for column_index in columns.clone() {
+                let source = match page.get_cell(row_index, column_index) {
+                    Some(Value::Text(text)) => text,
+                    _ => continue,
+                };
+                let normalized = match case {
+                    Some(TextCase::Upper) => source.trim().to_uppercase(),
+                    Some(TextCase::Lower) => source.trim().to_lowercase(),
+                    None => source.trim().to_owned(),
+                };
+                if &normalized == source {
+                    continue;
+                }
+                if let Err(err) = page.set_cell_from_string(row_index, column_index, &normalized) {
+                    utils::write_to_stderr(&format!(
+                        "WRN : Skipped cell \"({},{})\" =\n{}\n",
+                        row_index, column_index, err
+                    ))?;
+                    continue;
+                }
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Carry a last non-empty value into empty cells
+    ///
+    /// Rows are iterated from bottom to top if upward is true
+    fn fill_empty_cells(
+        &mut self,
+        page: &str,
+        column: Option<&str>,
+        upward: bool,
+    ) -> CedResult<usize> {
+        let page = self.get_page_data_mut(page)?;
+        let columns = match column {
+            Some(column) => {
+                let column_index = page.try_get_column_index(column).ok_or_else(|| {
+                    CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+                })?;
+                column_index..column_index + 1
+            }
+            None => 0..page.get_column_count(),
+        };
+        let mut count = 0;
+        for column_index in columns {
+            let mut last_value: Option<Value> = None;
+            let mut rows = (0..page.get_row_count()).collect::<Vec<_>>();
+            if upward {
+                rows.reverse();
+            }
+            for row_index in rows {
+                let value = match page.get_cell(row_index, column_index) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if !value.to_string().is_empty() {
+                    last_value.replace(value.clone());
+                    continue;
+                }
+                if let Some(last_value) = last_value.as_ref() {
+                    if let Err(err) = page.set_cell(row_index, column_index, last_value.clone()) {
+                        utils::write_to_stderr(&format!(
+                            "WRN : Skipped cell \"({},{})\" =\n{}\n",
+                            row_index, column_index, err
+                        ))?;
+                        continue;
+                    }
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
     /// Edit a row with values
     ///
     /// This assumes given input accords with order of a target record.
@@ -333,6 +1658,20 @@ impl Processor {
         Ok(())
     }
 
+    /// Append a row with default values at the end of a page
+    pub fn add_empty_row(&mut self, page: &str) -> CedResult<()> {
+        self.add_empty_rows(page, 1)
+    }
+
+    /// Append rows with default values at the end of a page
+    pub fn add_empty_rows(&mut self, page: &str, count: usize) -> CedResult<()> {
+        let page = self.get_page_data_mut(page)?;
+        for _ in 0..count {
+            page.insert_row(page.get_row_count(), None)?;
+        }
+        Ok(())
+    }
+
     /// Add a new row but from array of strings
     ///
     /// This assumes given input accords with order of a target record.
@@ -356,6 +1695,57 @@ impl Processor {
         Ok(())
     }
 
+    /// Insert rows from csv string starting from a row index
+    ///
+    /// Csv string should not have a header. Values are matched to columns by position.
+    ///
+    /// # Return
+    ///
+    /// Count of inserted rows
+    pub fn insert_rows_from_csv_string(
+        &mut self,
+        page: &str,
+        row_index: usize,
+        csv_src: &str,
+    ) -> CedResult<usize> {
+        let src_page = read_page(csv_src, false, None, true, None)?;
+        let columns = self.get_page_data(page)?.get_columns();
+        let mut rows = vec![];
+        for (index, row) in src_page.rows_iter().enumerate() {
+            if row.len() != columns.len() {
+                return Err(CedError::InvalidRowData(format!(
+                    "Row \"{}\" has \"{}\" values but page has \"{}\" columns",
+                    index,
+                    row.len(),
+                    columns.len()
+                )));
+            }
+            let values = row
+                .iter()
+                .zip(columns)
+                .map(|(value, column)| {
+                    let value = value.to_string();
+                    Value::from_str(&value, column.column_type).map_err(|err| {
+                        CedError::InvalidCellData {
+                            row: row_index + index,
+                            column: column.name.clone(),
+                            value,
+                            reason: err.to_string(),
+                        }
+                    })
+                })
+                .collect::<CedResult<Vec<_>>>()?;
+            rows.push(values);
+        }
+
+        let page = self.get_page_data_mut(page)?;
+        let count = rows.len();
+        for (offset, values) in rows.iter().enumerate() {
+            page.insert_row(row_index + offset, Some(values))?;
+        }
+        Ok(count)
+    }
+
     /// Add a new column into a page
     pub fn add_column(
         &mut self,
@@ -376,11 +1766,134 @@ impl Processor {
         Ok(())
     }
 
+    /// Copy a column into a new column
+    ///
+    /// New column has same type, limiter and values with the source column. Column is appended
+    /// to the last if column_index is none.
+    pub fn copy_column(
+        &mut self,
+        page: &str,
+        src: &str,
+        new_name: &str,
+        column_index: Option<usize>,
+    ) -> CedResult<()> {
+        let page = self.get_page_data_mut(page)?;
+        let mut src_index = page
+            .try_get_column_index(src)
+            .ok_or_else(|| CedError::InvalidColumn(format!("Column : \"{}\" is not valid", src)))?;
+        let column = page.get_columns()[src_index].clone();
+        let target_index = column_index.unwrap_or_else(|| page.get_column_count());
+        page.insert_column_with_type(
+            target_index,
+            new_name,
+            column.column_type,
+            Some(column.limiter),
+            None,
+        )?;
+        // Source column is pushed back when new column is inserted before it
+        if target_index <= src_index {
+            src_index += 1;
+        }
+        for row_index in 0..page.get_row_count() {
+            if let Some(value) = page.get_cell(row_index, src_index).cloned() {
+                page.set_cell(row_index, target_index, value)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Remove a row from a page
     pub fn remove_row(&mut self, page: &str, row_index: usize) -> CedResult<bool> {
         Ok(self.get_page_data_mut(page)?.delete_row(row_index))
     }
 
+    /// Remove every row of a page
+    ///
+    /// Columns and their limiters are kept
+    pub fn clear_page(&mut self, page: &str) -> CedResult<()> {
+        self.retain_rows(page, &[])?;
+        Ok(())
+    }
+
+    /// Remove rows whose column value matches a regex pattern
+    ///
+    /// # Return
+    ///
+    /// Count of deleted rows
+    pub fn delete_rows_matching(
+        &mut self,
+        page: &str,
+        column: &str,
+        pattern: &str,
+    ) -> CedResult<usize> {
+        let matched = self.find_matching_rows(page, column, pattern)?;
+        let page = self.get_page_data_mut(page)?;
+        // Delete from behind so that indices are not shifted
+        for row_index in matched.iter().rev() {
+            page.delete_row(*row_index);
+        }
+        Ok(matched.len())
+    }
+
+    /// Remove rows from start to end index inclusively
+    ///
+    /// # Return
+    ///
+    /// Count of removed rows
+    pub fn remove_row_range(&mut self, page: &str, start: usize, end: usize) -> CedResult<usize> {
+        let page = self.get_page_data_mut(page)?;
+        if start > end || end >= page.get_row_count() {
+            return Err(CedError::OutOfRangeError);
+        }
+        // Delete from behind so that indices are not shifted
+        for row_index in (start..=end).rev() {
+            page.delete_row(row_index);
+        }
+        Ok(end - start + 1)
+    }
+
+    /// Remove duplicate rows
+    ///
+    /// Whole row is compared if key_column is none. First occurrence of duplicates is kept.
+    ///
+    /// # Return
+    ///
+    /// Count of removed rows
+    pub fn deduplicate(&mut self, page: &str, key_column: Option<&str>) -> CedResult<usize> {
+        let page = self.get_page_data_mut(page)?;
+        let key_index = match key_column {
+            Some(column) => Some(page.try_get_column_index(column).ok_or_else(|| {
+                CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+            })?),
+            None => None,
+        };
+        let mut keys = HashSet::new();
+        let duplicates = page
+            .get_rows()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, row)| {
+                let key = match key_index {
+                    Some(key_index) => vec![row[key_index].to_string()],
+                    None => row
+                        .iter()
+                        .map(|value| value.to_string())
+                        .collect::<Vec<_>>(),
+                };
+                if keys.insert(key) {
+                    None
+                } else {
+                    Some(index)
+                }
+            })
+            .collect::<Vec<_>>();
+        // Delete from behind so that indices are not shifted
+        for row_index in duplicates.iter().rev() {
+            page.delete_row(*row_index);
+        }
+        Ok(duplicates.len())
+    }
+
     /// Remove a column from a page
     pub fn remove_column(&mut self, page: &str, column_index: usize) -> CedResult<()> {
         self.get_page_data_mut(page)?.delete_column(column_index)?;
@@ -411,12 +1924,43 @@ impl Processor {
         Ok(())
     }
 
+    /// Copy a row into a destination index
+    pub fn copy_row(&mut self, page: &str, src: usize, dest: usize) -> CedResult<()> {
+        self.get_page_data_mut(page)?.copy_row(src, dest)?;
+        Ok(())
+    }
+
+    /// Swap two rows in place
+    pub fn swap_rows(&mut self, page: &str, a: usize, b: usize) -> CedResult<()> {
+        self.get_page_data_mut(page)?.swap_rows(a, b)?;
+        Ok(())
+    }
+
+    /// Sort rows by a column
+    ///
+    /// Numbers are compared numerically and texts lexicographically. Rows with
+    /// equal values keep their original order.
+    pub fn sort_rows(&mut self, page: &str, column: &str, ascending: bool) -> CedResult<()> {
+        let page = self.get_page_data_mut(page)?;
+        let column_index = page.try_get_column_index(column).ok_or_else(|| {
+            CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+        })?;
+        page.sort_rows(column_index, ascending)?;
+        Ok(())
+    }
+
     /// Move a column from an index to a target index
     pub fn move_column(&mut self, page: &str, src: usize, target: usize) -> CedResult<()> {
         self.get_page_data_mut(page)?.move_column(src, target)?;
         Ok(())
     }
 
+    /// Swap two columns in place
+    pub fn swap_columns(&mut self, page: &str, a: usize, b: usize) -> CedResult<()> {
+        self.get_page_data_mut(page)?.swap_columns(a, b)?;
+        Ok(())
+    }
+
     /// Rename a column into a new name
     pub fn rename_column(&mut self, page: &str, column: &str, new_name: &str) -> CedResult<()> {
         let page = self.get_page_data_mut(page)?;
@@ -428,7 +1972,69 @@ impl Processor {
         Ok(())
     }
 
+    /// Transpose rows and columns of a page
+    ///
+    /// This is only available for data page
+    pub fn transpose(&mut self, page: &str) -> CedResult<()> {
+        self.get_page_data_mut(page)?.transpose()?;
+        Ok(())
+    }
+
+    /// Infer schema from page's data
+    ///
+    /// A column is inferred as number if every non-empty value is an integer and its default is 0
+    /// so that empty cells qualify when the schema is applied. Text column's
+    /// pattern is taken from email, date or url preset if every non-empty value matches the
+    /// preset.
+    pub fn infer_schema(&self, page: &str) -> CedResult<String> {
+        let page = self.get_page_data(page)?;
+        let mut schema = format!("{}\n", SCHEMA_HEADER);
+        for (column_index, column) in page.get_columns().iter().enumerate() {
+            let values = (0..page.get_row_count())
+                .filter_map(|row_index| page.get_cell(row_index, column_index))
+                .map(|value| value.to_string())
+                .filter(|value| !value.is_empty())
+                .collect::<Vec<_>>();
+
+            let is_number =
+                !values.is_empty() && values.iter().all(|value| value.parse::<isize>().is_ok());
+            let (value_type, default, pattern) = if is_number {
+                (ValueType::Number, String::from("0"), String::new())
+            } else {
+                let preset = ["email", "date", "url"]
+                    .iter()
+                    .filter_map(|name| self.preset.get(name))
+                    .find(|limiter| {
+                        !values.is_empty()
+                            && values
+                                .iter()
+                                .all(|value| limiter.qualify(&Value::Text(value.to_owned())))
+                    });
+                match preset
+                    .and_then(|limiter| Some((limiter.get_default()?, limiter.get_pattern()?)))
+                {
+                    Some((default, pattern)) => (
+                        ValueType::Text,
+                        default.to_string(),
+                        pattern.as_str().to_owned(),
+                    ),
+                    None => (ValueType::Text, String::new(), String::new()),
+                }
+            };
+            schema.push_str(&format!(
+                "{},{},{},,{}\n",
+                utils::quote_field(&column.name, ','),
+                value_type,
+                utils::quote_field(&default, ','),
+                utils::quote_field(&pattern, ',')
+            ));
+        }
+        Ok(schema)
+    }
+
     /// Export page's schema
+    ///
+    /// Column's range is written in a variant field such as "[0,150]"
     pub fn export_schema(&self, page: &str) -> CedResult<String> {
         let page = self.get_page_data(page)?;
         if page.is_array() {
@@ -438,7 +2044,26 @@ impl Processor {
         }
         if !page.is_array() {
             // Sincie it is not an array, it is ok to unwrap
-            Ok(page.get_data().unwrap().export_schema())
+            let schema = page.get_data().unwrap().export_schema();
+            if page.ranges.is_empty() {
+                return Ok(schema);
+            }
+            // Range is written in a variant field
+            let mut exported = String::new();
+            for (index, line) in schema.lines().enumerate() {
+                if index == 0 {
+                    exported.push_str(line);
+                    exported.push('\n');
+                    continue;
+                }
+                let mut row_args = dcsv::utils::csv_row_to_vector(line, None, false);
+                if let Some(range) = row_args.first().and_then(|name| page.ranges.get(name)) {
+                    row_args[3] = format!("\"{}\"", range);
+                }
+                exported.push_str(&row_args.join(","));
+                exported.push('\n');
+            }
+            Ok(exported)
         } else {
             Err(CedError::InvalidPageOperation(
                 "Cannot export schmea when csv is imported as array".to_string(),
@@ -455,19 +2080,36 @@ impl Processor {
     /// * panic : Whether to panic if current value fails to qualify schema. If not every
     /// unqualified values are overwritten to default qualifying values.
     pub fn set_schema(&mut self, page: &str, path: impl AsRef<Path>, panic: bool) -> CedResult<()> {
-        if self.get_page_data_mut(page)?.is_array() {
-            return Err(CedError::InvalidPageOperation(
-                "Cannot set schema in array mode".to_string(),
-            ));
-        }
-
         let content = std::fs::read_to_string(&path).map_err(|err| {
             CedError::io_error(
                 err,
                 &format!("Failed to import file \"{}\"", path.as_ref().display()),
             )
         })?;
-        let mut content = content.lines();
+        self.set_schema_from_string(page, &content, panic)
+    }
+
+    /// Apply schema from a string into a given page
+    ///
+    /// # Args
+    ///
+    /// * page : Page name
+    /// * schema_content : Schema csv content including a header
+    /// * panic : Whether to panic if current value fails to qualify schema. If not every
+    ///   unqualified values are overwritten to default qualifying values.
+    pub fn set_schema_from_string(
+        &mut self,
+        page: &str,
+        schema_content: &str,
+        panic: bool,
+    ) -> CedResult<()> {
+        if self.get_page_data_mut(page)?.is_array() {
+            return Err(CedError::InvalidPageOperation(
+                "Cannot set schema in array mode".to_string(),
+            ));
+        }
+
+        let mut content = schema_content.lines();
 
         let header = content.next();
         if header.is_none() {
@@ -478,9 +2120,11 @@ impl Processor {
 
         let mut row = content.next();
         while let Some(row_src) = row {
-            let row_args = dcsv::utils::csv_row_to_vector(row_src, None, false);
+            let mut row_args = dcsv::utils::csv_row_to_vector(row_src, None, false);
+            let range = take_schema_range(&mut row_args)?;
             let limiter = ValueLimiter::from_line(&row_args[1..].to_vec())?;
             self.set_limiter(page, &row_args[0], &limiter, panic)?;
+            self.set_range(page, &row_args[0], range)?;
             row = content.next();
         }
         Ok(())
@@ -516,16 +2160,67 @@ impl Processor {
         Ok(())
     }
 
+    /// Set an inclusive range to a column
+    ///
+    /// Range is checked along with column's limiter by validation and set_cell_if_valid. None
+    /// removes a range from a column.
+    pub fn set_range(
+        &mut self,
+        page: &str,
+        column: &str,
+        range: Option<ValueRange>,
+    ) -> CedResult<()> {
+        if self.get_page_data(page)?.is_array() {
+            return Err(CedError::InvalidPageOperation(String::from(
+                "Cannot set range for virtual array",
+            )));
+        }
+        let column_index = self.get_column_index(page, column)?;
+        let page = self.get_page_data_mut(page)?;
+        let column = &page.get_columns()[column_index];
+        let name = column.name.clone();
+        match range {
+            Some(range) => {
+                if range.get_type() != column.column_type {
+                    return Err(CedError::InvalidColumn(format!(
+                        "Range \"{}\" is not a type of column \"{}\"",
+                        range, name
+                    )));
+                }
+                page.ranges.insert(name, range);
+            }
+            None => {
+                page.ranges.remove(&name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a range of a column
+    pub fn get_range(&self, page: &str, column: &str) -> CedResult<Option<&ValueRange>> {
+        let column_index = self.get_column_index(page, column)?;
+        let page = self.get_page_data(page)?;
+        let name = &page.get_columns()[column_index].name;
+        Ok(page.ranges.get(name))
+    }
+
     // <PRESETS>
     //
-    #[cfg(feature = "cli")]
-    pub(crate) fn configure_preset(&mut self, use_defualt: bool) -> CedResult<()> {
+    /// Load presets
+    ///
+    /// Presets are read from a global preset file. Built-in presets are only available with
+    /// "presets" feature.
+    ///
+    /// * use_defualt : Whether to include built-in presets
+    pub fn configure_preset(&mut self, use_defualt: bool) -> CedResult<()> {
         self.preset = Preset::new(use_defualt)?;
         Ok(())
     }
 
-    #[cfg(feature = "cli")]
-    pub(crate) fn set_limiter_from_preset(
+    /// Set a limiter to a column from a preset
+    ///
+    /// This does nothing if given preset doesn't exist
+    pub fn set_limiter_from_preset(
         &mut self,
         page: &str,
         column: &str,
@@ -548,9 +2243,154 @@ impl Processor {
         Ok(self.get_page_data(page)?.get_column_count())
     }
 
+    /// Get statistics of a column
+    ///
+    /// Min, max, sum and mean are only calculated for number columns. Empty cells are counted as
+    /// null.
+    pub fn column_stats(&self, page: &str, column: &str) -> CedResult<ColumnStats> {
+        let column_index = self.get_column_index(page, column)?;
+        let page = self.get_page_data(page)?;
+        let is_number = page.get_columns()[column_index].column_type == ValueType::Number;
+
+        let mut stats = ColumnStats::default();
+        let mut uniques = HashSet::new();
+        let mut sum = 0f64;
+        for row_index in 0..page.get_row_count() {
+            let value = match page.get_cell(row_index, column_index) {
+                Some(value) => value,
+                None => continue,
+            };
+            stats.count += 1;
+            if value.to_string().is_empty() {
+                stats.null_count += 1;
+                continue;
+            }
+            uniques.insert(value.to_string());
+            if let Value::Number(num) = value {
+                sum += *num as f64;
+                let is_less = |other: &Value| utils::compare_values(value, other).is_lt();
+                if stats.min.as_ref().map(is_less).unwrap_or(true) {
+                    stats.min = Some(value.clone());
+                }
+                let is_greater = |other: &Value| utils::compare_values(value, other).is_gt();
+                if stats.max.as_ref().map(is_greater).unwrap_or(true) {
+                    stats.max = Some(value.clone());
+                }
+            }
+        }
+        stats.unique_count = uniques.len();
+
+        if is_number {
+            let valid_count = stats.count - stats.null_count;
+            stats.sum = Some(sum);
+            if valid_count != 0 {
+                stats.mean = Some(sum / valid_count as f64);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Check every cell against its column's type and limiter
+    ///
+    /// This doesn't modify a page. Array page cannot be validated because it has no limiters.
+    pub fn validate_page(&self, page: &str) -> CedResult<Vec<ValidationError>> {
+        let page = self.get_page_data(page)?;
+        if page.is_array() {
+            return Err(CedError::InvalidPageOperation(
+                "Cannot validate virtual array".to_string(),
+            ));
+        }
+        let mut errors = vec![];
+        for row_index in 0..page.get_row_count() {
+            for (column_index, column) in page.get_columns().iter().enumerate() {
+                let value = match page.get_cell(row_index, column_index) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                let range = page.ranges.get(&column.name);
+                let reason = match check_value(value, column.column_type, &column.limiter, range) {
+                    Some(reason) => reason,
+                    None => continue,
+                };
+                errors.push(ValidationError {
+                    row: row_index,
+                    column: column.name.clone(),
+                    value: value.to_string(),
+                    reason,
+                });
+            }
+        }
+        Ok(errors)
+    }
+
+    /// Check every cell against a schema file without applying it
+    ///
+    /// Values are converted into schema's type before check. This doesn't modify a page.
+    pub fn validate_against_schema(
+        &self,
+        page: &str,
+        schema_path: impl AsRef<Path>,
+    ) -> CedResult<Vec<ValidationError>> {
+        let content = std::fs::read_to_string(&schema_path).map_err(|err| {
+            CedError::io_error(
+                err,
+                &format!(
+                    "Failed to import file \"{}\"",
+                    schema_path.as_ref().display()
+                ),
+            )
+        })?;
+        let page_name = page;
+        let page = self.get_page_data(page)?;
+        let mut errors = vec![];
+        // First line is a header
+        for line in content
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+        {
+            let mut row_args = dcsv::utils::csv_row_to_vector(line, None, false);
+            let range = take_schema_range(&mut row_args)?;
+            let limiter = ValueLimiter::from_line(&row_args[1..])?;
+            let column = &row_args[0];
+            let column_index = self.get_column_index(page_name, column)?;
+            for row_index in 0..page.get_row_count() {
+                let value = match page.get_cell(row_index, column_index) {
+                    Some(value) => value.to_string(),
+                    None => continue,
+                };
+                let reason = match Value::from_str(&value, limiter.get_type()) {
+                    Ok(converted) => {
+                        check_value(&converted, limiter.get_type(), &limiter, range.as_ref())
+                    }
+                    Err(_) => Some(format!(
+                        "Cannot be converted to type \"{}\"",
+                        limiter.get_type()
+                    )),
+                };
+                if let Some(reason) = reason {
+                    errors.push(ValidationError {
+                        row: row_index,
+                        column: column.to_owned(),
+                        value,
+                        reason,
+                    });
+                }
+            }
+        }
+        Ok(errors)
+    }
+
     /// Get last row index
-    pub fn last_row_index(&self, page: &str) -> CedResult<usize> {
-        Ok(self.get_page_data(page)?.get_row_count().max(1) - 1)
+    ///
+    /// This returns None if page has no rows
+    pub fn last_row_index(&self, page: &str) -> CedResult<Option<usize>> {
+        Ok(self.get_page_data(page)?.get_row_count().checked_sub(1))
+    }
+
+    /// Check if page has no rows
+    pub fn is_empty_page(&self, page: &str) -> CedResult<bool> {
+        Ok(self.get_page_data(page)?.get_row_count() == 0)
     }
 
     /// Get last column index
@@ -559,8 +2399,97 @@ impl Processor {
     }
 
     /// Get virtual data as string form
+    ///
+    /// Masked columns are not included
     pub fn get_page_as_string(&self, page: &str) -> CedResult<String> {
-        Ok(self.get_page_data(page)?.to_string())
+        let page_data = self.get_page_data(page)?;
+        if page_data.masked_columns.is_empty() {
+            return Ok(page_data.to_string());
+        }
+        let columns = page_data
+            .get_visible_column_indices()
+            .into_iter()
+            .map(|index| page_data.get_columns()[index].name.as_str())
+            .collect::<Vec<_>>();
+        self.get_page_as_string_with_columns(page, &columns)
+    }
+
+    /// Hide a column from print output
+    ///
+    /// Masked columns are still written by write and export.
+    ///
+    /// # Return
+    ///
+    /// Whether column was newly masked
+    pub fn mask_column(&mut self, page: &str, column: &str) -> CedResult<bool> {
+        self.get_page_data_mut(page)?.mask_column(column)
+    }
+
+    /// Show a masked column again
+    ///
+    /// # Return
+    ///
+    /// Whether column was masked
+    pub fn unmask_column(&mut self, page: &str, column: &str) -> CedResult<bool> {
+        Ok(self.get_page_data_mut(page)?.unmask_column(column))
+    }
+
+    /// Get names of masked columns sorted
+    pub fn get_masked_columns(&self, page: &str) -> CedResult<Vec<String>> {
+        let mut columns = self
+            .get_page_data(page)?
+            .masked_columns
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        columns.sort();
+        Ok(columns)
+    }
+
+    /// Get virtual data as string form with only given columns
+    ///
+    /// Columns are written in given order
+    pub fn get_page_as_string_with_columns(
+        &self,
+        page: &str,
+        columns: &[&str],
+    ) -> CedResult<String> {
+        let page = self.get_page_data(page)?;
+        let indices = columns
+            .iter()
+            .map(|column| {
+                page.try_get_column_index(column).ok_or_else(|| {
+                    CedError::InvalidColumn(format!("Column : \"{}\" is not valid", column))
+                })
+            })
+            .collect::<CedResult<Vec<_>>>()?;
+
+        let mut csv = columns
+            .iter()
+            .map(|column| utils::quote_field(column, ','))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push('\n');
+        for row in page.rows_iter() {
+            let row = indices
+                .iter()
+                .map(|&index| utils::quote_field(&row[index].to_string(), ','))
+                .collect::<Vec<_>>()
+                .join(",");
+            csv.push_str(&row);
+            csv.push('\n');
+        }
+        Ok(csv)
+    }
+
+    /// Iterate page's rows lazily
+    ///
+    /// Each row yields values in the same order as columns.
+    pub fn rows_iter<'a>(
+        &'a self,
+        page: &str,
+    ) -> CedResult<impl Iterator<Item = Vec<&'a Value>> + 'a> {
+        Ok(self.get_page_data(page)?.rows_iter())
     }
 
     /// Get cell from page
@@ -575,6 +2504,59 @@ impl Processor {
         Ok(self.get_page_data(page)?.get_cell(row_index, column_index))
     }
 
+    /// Get cell from page as string
+    ///
+    /// This fails when page doesn't exist
+    pub fn get_cell_as_string(
+        &self,
+        page: &str,
+        row_index: usize,
+        column_index: usize,
+    ) -> CedResult<Option<String>> {
+        self.get_cell(page, row_index, column_index)
+            .map(|value| value.map(|v| v.to_string()))
+    }
+
+    /// Get cell from page as a string reference without copy
+    ///
+    /// This returns none for number values because they don't have a string representation
+    /// to borrow from. Use get_cell_as_string instead.
+    pub fn get_cell_as_str_ref(
+        &self,
+        page: &str,
+        row_index: usize,
+        column_index: usize,
+    ) -> CedResult<Option<&str>> {
+        Ok(match self.get_cell(page, row_index, column_index)? {
+            Some(Value::Text(text)) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Check if a column exists in a page
+    ///
+    /// This fails when page doesn't exist
+    pub fn column_exists(&self, page: &str, column: &str) -> CedResult<bool> {
+        Ok(self
+            .get_page_data(page)?
+            .try_get_column_index(column)
+            .is_some())
+    }
+
+    /// Get index of a column by name
+    ///
+    /// This fails when either page or column doesn't exist
+    pub fn get_column_index(&self, page: &str, column: &str) -> CedResult<usize> {
+        self.get_page_data(page)?
+            .try_get_column_index(column)
+            .ok_or_else(|| {
+                CedError::InvalidColumn(format!(
+                    "Column : \"{}\" is not valid in page \"{}\"",
+                    column, page
+                ))
+            })
+    }
+
     /// Get column from page
     ///
     /// This fails when either page or column doesn't exist
@@ -593,4 +2575,169 @@ impl Processor {
             None => None,
         })
     }
+
+    /// Get column names of a page
+    pub fn get_header(&self, page: &str) -> CedResult<Vec<String>> {
+        Ok(self
+            .get_page_data(page)?
+            .get_columns()
+            .iter()
+            .map(|column| column.name.clone())
+            .collect())
+    }
+
+    /// Get column names of a page with their types
+    pub fn get_header_with_types(&self, page: &str) -> CedResult<Vec<(String, ValueType)>> {
+        Ok(self
+            .get_page_data(page)?
+            .get_columns()
+            .iter()
+            .map(|column| (column.name.clone(), column.column_type))
+            .collect())
+    }
+
+    /// Get unique values of a column
+    ///
+    /// Values are sorted numerically for number column and lexicographically for text column.
+    pub fn unique_values(&self, page: &str, column: &str) -> CedResult<Vec<Value>> {
+        Ok(self
+            .unique_value_counts(page, column)?
+            .into_iter()
+            .map(|(value, _)| value)
+            .collect())
+    }
+
+    /// Get unique values of a column with count of rows for each value
+    ///
+    /// Sorted the same way as unique_values.
+    pub fn unique_value_counts(&self, page: &str, column: &str) -> CedResult<Vec<(Value, usize)>> {
+        let mut values = self.get_column_values(page, column)?;
+        values.sort_by(|lhs, rhs| utils::compare_values(lhs, rhs));
+
+        let mut counts: Vec<(Value, usize)> = vec![];
+        for value in values {
+            match counts.last_mut() {
+                Some((last, count)) if utils::compare_values(last, value) == Ordering::Equal => {
+                    *count += 1
+                }
+                _ => counts.push((value.clone(), 1)),
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Get page's rows as owned strings
+    pub fn get_rows_as_strings(&self, page: &str) -> CedResult<Vec<Vec<String>>> {
+        Ok(self
+            .get_page_data(page)?
+            .rows_iter()
+            .map(|row| row.iter().map(|value| value.to_string()).collect())
+            .collect())
+    }
+
+    /// Get page's rows as maps from column name to value
+    ///
+    /// This is same with get_data_as_vec_of_maps
+    pub fn get_rows_as_maps(&self, page: &str) -> CedResult<Vec<HashMap<String, String>>> {
+        self.get_data_as_vec_of_maps(page)
+    }
+
+    /// Get page's rows as maps from column name to value
+    pub fn get_data_as_vec_of_maps(&self, page: &str) -> CedResult<Vec<HashMap<String, String>>> {
+        let page = self.get_page_data(page)?;
+        let columns = page.get_columns();
+        let maps = page
+            .rows_iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| (column.name.clone(), value.to_string()))
+                    .collect()
+            })
+            .collect();
+        Ok(maps)
+    }
+
+    /// Get all values of a column in row order
+    ///
+    /// This fails when either page or column doesn't exist
+    pub fn get_column_values(&self, page: &str, column: &str) -> CedResult<Vec<&Value>> {
+        let column_index = self.get_column_index(page, column)?;
+        let page = self.get_page_data(page)?;
+        Ok(page
+            .rows_iter()
+            .filter_map(|row| row.get(column_index).copied())
+            .collect())
+    }
+}
+
+/// Read csv content into a page
+fn read_page(
+    data: &str,
+    has_header: bool,
+    line_ending: Option<char>,
+    raw_mode: bool,
+    delimiter: Option<char>,
+) -> CedResult<Page> {
+    let mut ignore_empty_row = true;
+    if let Ok(val) = std::env::var("CED_READ_STRICT") {
+        if val.to_lowercase() == "true" {
+            ignore_empty_row = false;
+        }
+    }
+    let mut reader = dcsv::Reader::new()
+        .use_delimiter(delimiter.unwrap_or(','))
+        .use_line_delimiter(line_ending.unwrap_or('\n'))
+        .has_header(has_header)
+        .ignore_empty_row(ignore_empty_row);
+
+    let page = if raw_mode {
+        Page::new_array(reader.array_from_stream(data.as_bytes())?)
+    } else {
+        Page::new_data(reader.data_from_stream(data.as_bytes())?)
+    };
+    Ok(page)
+}
+
+/// Check a value against a type, a limiter and an optional range
+///
+/// This returns a reason if value is not valid
+fn check_value(
+    value: &Value,
+    value_type: ValueType,
+    limiter: &ValueLimiter,
+    range: Option<&ValueRange>,
+) -> Option<String> {
+    if value.get_type() != value_type {
+        Some(format!(
+            "Expected type \"{}\" but got \"{}\"",
+            value_type,
+            value.get_type()
+        ))
+    } else if !limiter.qualify(value) {
+        Some(format!("Doesn't qualify limiter \"{}\"", limiter))
+    } else {
+        range
+            .filter(|range| !range.contains(value))
+            .map(|range| format!("Out of range \"{}\"", range))
+    }
+}
+
+/// Take a range out of a schema row
+///
+/// Variant field of a schema row can be a range such as "[0,150]" instead of variants. Variant
+/// field is emptied so that the rest of a row can be read as a limiter.
+fn take_schema_range(row_args: &mut [String]) -> CedResult<Option<ValueRange>> {
+    if row_args.len() != dcsv::LIMITER_ATTRIBUTE_LEN + 1 {
+        return Ok(None);
+    }
+    let variant = row_args[3].trim().trim_matches('"');
+    if !variant.starts_with('[') {
+        return Ok(None);
+    }
+    let value_type = row_args[1].parse::<ValueType>()?;
+    let range = ValueRange::parse(variant, value_type)?;
+    row_args[3].clear();
+    Ok(Some(range))
 }
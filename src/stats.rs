@@ -0,0 +1,43 @@
+use dcsv::Value;
+use std::fmt::Display;
+
+/// Statistics of a column
+///
+/// Min, max, sum and mean are only available for number columns
+#[derive(Debug, Clone, Default)]
+pub struct ColumnStats {
+    /// Count of all cells
+    pub count: usize,
+    /// Count of empty cells
+    pub null_count: usize,
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+    pub sum: Option<f64>,
+    /// Mean of non-empty cells
+    pub mean: Option<f64>,
+    /// Count of distinct non-empty values
+    pub unique_count: usize,
+}
+
+impl Display for ColumnStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "count={} null={} unique={}",
+            self.count, self.null_count, self.unique_count
+        )?;
+        if let Some(min) = &self.min {
+            write!(f, " min={}", min)?;
+        }
+        if let Some(max) = &self.max {
+            write!(f, " max={}", max)?;
+        }
+        if let Some(sum) = self.sum {
+            write!(f, " sum={}", sum)?;
+        }
+        if let Some(mean) = self.mean {
+            write!(f, " mean={}", mean)?;
+        }
+        Ok(())
+    }
+}
@@ -11,22 +11,22 @@ fn command_test() -> CedResult<()> {
     use crate::Processor;
     let mut processor = Processor::new();
     processor
-        .import_from_file("test.csv", true, None, false)
+        .import_from_file("test.csv", true, None, false, None)
         .unwrap();
     let page_name = processor.get_cursor().unwrap();
     processor
         .add_row_from_string_array(
             &page_name,
-            processor.last_row_index(&page_name)?,
+            processor.last_row_index(&page_name)?.unwrap_or(0),
             &["a", "b"],
         )
         .unwrap();
 
-    processor.overwrite_to_file(&page_name, true).unwrap();
+    processor.overwrite_to_file(&page_name, true, None).unwrap();
     let mut processor = Processor::new();
 
     processor
-        .import_from_file("test.csv", true, None, false)
+        .import_from_file("test.csv", true, None, false, None)
         .unwrap();
 
     // Get current cursor(page_name) for later uses
@@ -35,13 +35,472 @@ fn command_test() -> CedResult<()> {
     // Processor can hold multiple pages and needs page_name for every operation to work on the
     // page
     processor
-        .add_row_from_strings(
+        .add_row_from_string_array(
             &page_name,
-            processor.last_row_index(&page_name)?,
+            processor.last_row_index(&page_name)?.unwrap_or(0),
             &["a", "b"],
         )
         .unwrap();
 
-    processor.overwrite_to_file(&page_name, true).unwrap();
+    processor.overwrite_to_file(&page_name, true, None).unwrap();
+    Ok(())
+}
+
+#[test]
+fn schema_from_string_test() -> CedResult<()> {
+    use crate::Processor;
+    use dcsv::ValueType;
+    let mut processor = Processor::new();
+    processor.add_page("schema", "id,name\n1,john\n2,jane", true, None, false, None)?;
+    processor.set_schema_from_string(
+        "schema",
+        "column,type,default,variant,pattern\nid,number,0,,",
+        true,
+    )?;
+
+    let column = processor.get_column_by_name("schema", "id")?.unwrap();
+    assert_eq!(column.column_type, ValueType::Number);
+    assert!(processor.edit_cell("schema", 0, 0, "text").is_err());
+    assert!(!processor.set_cell_if_valid("schema", 0, 0, "text")?);
+    assert!(processor.set_cell_if_valid("schema", 0, 0, "3")?);
+
+    // Invalid values should fail with panic option
+    processor.add_page("invalid", "id,name\njohn,1", true, None, false, None)?;
+    assert!(processor
+        .set_schema_from_string(
+            "invalid",
+            "column,type,default,variant,pattern\nid,number,0,,",
+            true
+        )
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn export_json_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    processor.add_page("json", "id,name,note\n1,jo\\hn,", true, None, false, None)?;
+    processor.set_schema_from_string(
+        "json",
+        "column,type,default,variant,pattern\nid,number,0,,",
+        true,
+    )?;
+    assert_eq!(
+        processor.export_as_json("json")?,
+        "[\n  {\"id\":1,\"name\":\"jo\\\\hn\",\"note\":null}\n]\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn insert_rows_from_csv_string_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    processor.add_page("rows", "id,name\n1,john\n4,jane", true, None, false, None)?;
+    let count = processor.insert_rows_from_csv_string("rows", 1, "2,tom\n3,ann")?;
+    assert_eq!(count, 2);
+    assert_eq!(processor.get_row_count("rows")?, 4);
+    let names = processor.get_column_values("rows", "name")?;
+    assert_eq!(
+        names.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        vec!["john", "tom", "ann", "jane"]
+    );
+
+    // Column count should match
+    assert!(processor
+        .insert_rows_from_csv_string("rows", 0, "5")
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn invalid_row_data_test() -> CedResult<()> {
+    use crate::{CedError, Processor};
+    use dcsv::Value;
+    let mut processor = Processor::new();
+    processor.add_page("rows", "id,name\n1,john", true, None, false, None)?;
+    processor.set_schema_from_string(
+        "rows",
+        "column,type,default,variant,pattern\nid,Number,0,,\nname,Text,,,",
+        true,
+    )?;
+    let invalid = vec![Value::Text("x".to_owned()), Value::Text("tom".to_owned())];
+    let failed_cell = |result: CedResult<_>| match result {
+        Err(CedError::InvalidCellData { row, column, .. }) => Some((row, column)),
+        _ => None,
+    };
+    assert_eq!(
+        failed_cell(processor.add_row("rows", 1, Some(&invalid)).map(|_| ())),
+        Some((1, "id".to_owned()))
+    );
+    let edit = vec![Some(Value::Text("x".to_owned())), None];
+    assert_eq!(
+        failed_cell(processor.edit_row("rows", 0, &edit).map(|_| ())),
+        Some((0, "id".to_owned()))
+    );
+    assert_eq!(
+        failed_cell(processor.set_row("rows", 0, &invalid).map(|_| ())),
+        Some((0, "id".to_owned()))
+    );
+    assert_eq!(
+        failed_cell(
+            processor
+                .insert_rows_from_csv_string("rows", 1, "2,tom\nx,ann")
+                .map(|_| ())
+        ),
+        Some((2, "id".to_owned()))
+    );
+    Ok(())
+}
+
+#[test]
+fn command_names_test() {
+    use crate::command::{CommandType, COMMAND_NAMES};
+    use std::str::FromStr;
+    // Every suggestion should be a valid command
+    for name in COMMAND_NAMES.iter() {
+        assert_ne!(CommandType::from_str(name).unwrap(), CommandType::None);
+    }
+    assert!(CommandType::from_str("imoprt").is_err());
+}
+
+#[test]
+fn execute_comment_test() -> CedResult<()> {
+    use crate::{Command, CommandType, Processor};
+    use std::str::FromStr;
+    assert_eq!(Command::from_str("")?.command_type, CommandType::None);
+
+    let mut processor = Processor::new();
+    processor.set_print_logs(false);
+    processor.add_page("script", "id,name", true, None, false, None)?;
+    processor.execute_script_from_string(
+        "# Comment line\n\nadd-row 0 1,john ; # Inline comment ; add-row 0 2,jane\n",
+    )?;
+    assert_eq!(processor.get_row_count("script")?, 1);
+    processor.execute_script_from_string(
+        "add-row 0 3,a ;  # note ; add-row 0 4,b\nadd-row 0 5,\"#b\"",
+    )?;
+    assert_eq!(processor.get_row_count("script")?, 3);
+    assert_eq!(processor.get_cell_as_string("script", 1, 0)?.unwrap(), "3");
+    assert_eq!(
+        processor.get_cell_as_string("script", 0, 1)?.unwrap(),
+        "\"#b\""
+    );
+
+    // "#" inside of arguments is not a comment
+    processor.execute_script_from_string("edit-cell 0,1 #fff ; add-row 0 '6,a #1'")?;
+    assert_eq!(
+        processor.get_cell_as_string("script", 1, 1)?.unwrap(),
+        "#fff"
+    );
+    assert_eq!(
+        processor.get_cell_as_string("script", 0, 1)?.unwrap(),
+        "a #1"
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_arg_error_test() -> CedResult<()> {
+    use crate::{CedError, Command, Processor};
+    use std::str::FromStr;
+    let mut processor = Processor::new();
+    processor.set_print_logs(false);
+    processor.add_page("parse", "id\n1\n2", true, None, false, None)?;
+    match processor.execute_command(&Command::from_str("move-row x 1")?) {
+        Err(CedError::ParseError(msg)) => assert!(msg.contains("row argument \"x\"")),
+        _ => panic!("Expected a parse error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn infer_schema_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    processor.add_page("infer", "id,name\n1,john\n,jane", true, None, false, None)?;
+    let schema = processor.infer_schema("infer")?;
+    assert!(schema.contains("id,Number,0,,"));
+    processor.set_schema_from_string("infer", &schema, false)?;
+    assert_eq!(processor.get_cell_as_string("infer", 1, 0)?.unwrap(), "0");
+    Ok(())
+}
+
+#[test]
+fn is_valid_csv_test() {
+    use crate::utils::is_valid_csv;
+    assert!(is_valid_csv("\"first line\nsecond line\""));
+    assert!(is_valid_csv("\"first line\r\nsecond line\""));
+    assert!(is_valid_csv("\"say \"\"\nhi\"\"\""));
+    assert!(is_valid_csv("\"line\n\"\"\""));
+    assert!(is_valid_csv("\"a,b\""));
+    assert!(!is_valid_csv("a,b"));
+    assert!(is_valid_csv("\"say \"\"hi\"\"\""));
+    assert!(!is_valid_csv("\"unfinished"));
+}
+
+#[test]
+fn tokens_with_quote_test() {
+    use crate::utils::tokens_with_quote;
+    assert_eq!(
+        tokens_with_quote("add-row 0 1,\"hello, world\""),
+        vec!["add-row", "0", "1,\"hello, world\""]
+    );
+    assert_eq!(
+        tokens_with_quote("edit 0,a \"say \"\"it's\"\"\""),
+        vec!["edit", "0,a", "\"say \"\"it's\"\"\""]
+    );
+    assert_eq!(
+        tokens_with_quote("edit 0,a 'a b'"),
+        vec!["edit", "0,a", "a b"]
+    );
+    assert_eq!(
+        crate::utils::tokens_without_double_quote("filter name \"^a b\" \"say \"\"hi\"\"\""),
+        vec!["filter", "name", "^a b", "say \"hi\""]
+    );
+
+    // Double quotes are only kept for csv values
+    use crate::Command;
+    use std::str::FromStr;
+    let command = Command::from_str("set FILE \"my file.csv\"").unwrap();
+    assert_eq!(command.arguments, vec!["FILE", "my file.csv"]);
+    let command = Command::from_str("edit 0,0 \"a,b\"").unwrap();
+    assert_eq!(command.arguments, vec!["0,0", "\"a,b\""]);
+}
+
+#[test]
+fn add_row_with_quoted_comma_test() -> CedResult<()> {
+    use crate::{Command, Processor};
+    use std::str::FromStr;
+    let mut processor = Processor::new();
+    processor.set_print_logs(false);
+    processor.add_page("quote", "id,name\n1,john", true, None, false, None)?;
+    processor.execute_command(&Command::from_str("add-row 1 2,\"hello, world\"")?)?;
+    assert_eq!(processor.get_row_count("quote")?, 2);
+    let names = processor.get_column_values("quote", "name")?;
+    assert_eq!(names[1].to_string(), "\"hello, world\"");
+    Ok(())
+}
+
+#[test]
+fn ext_test() -> CedResult<()> {
+    use crate::{ColumnExt, LimiterExt};
+    use dcsv::{Value, ValueLimiter, ValueType};
+    let text = |s: &str| Value::Text(s.to_owned());
+    let limiter = ValueLimiter::from_variants(text("b"), vec![text("a"), text("b")])?;
+    assert!(limiter.qualify(&text("a")));
+    assert!(!limiter.qualify(&text("c")));
+    assert!(ValueLimiter::from_variants(text("c"), vec![text("a")]).is_err());
+
+    let limiter = ValueLimiter::from_pattern(ValueType::Text, text("000"), "^[0-9]+$")?;
+    assert!(!limiter.qualify(&text("abc")));
+    assert!(ValueLimiter::from_pattern(ValueType::Number, text("0"), "^[0-9]+$").is_err());
+    assert!(ValueLimiter::from_type(ValueType::Number).qualify(&Value::Number(3)));
+
+    let column = dcsv::Column::new("age", ValueType::Text, None)
+        .with_limiter(ValueLimiter::from_type(ValueType::Number))
+        .with_default(Value::Number(0))?;
+    assert_eq!(column.column_type, ValueType::Number);
+    assert_eq!(column.limiter.get_default(), Some(&Value::Number(0)));
+    let column = dcsv::Column::empty("flag")
+        .with_limiter(ValueLimiter::from_variants(
+            text("a"),
+            vec![text("a"), text("b")],
+        )?)
+        .with_default(text("b"))?;
+    assert_eq!(column.limiter.get_default(), Some(&text("b")));
+    assert!(column.limiter.qualify(&text("a")));
+    assert!(dcsv::Column::empty("flag")
+        .with_default(Value::Number(0))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cli")]
+fn no_log_flag_test() {
+    use crate::cli::parse::{FlagType, Parser};
+    for flag in ["--no-log", "-L", "--nolog", "-n"] {
+        let flags = Parser::new().parse_from_vec(&vec![flag]);
+        assert_eq!(flags[0].ftype, FlagType::NoLog);
+    }
+}
+
+#[test]
+fn no_log_test() -> CedResult<()> {
+    use crate::utils::TEST_STDOUT;
+    use crate::{Command, Processor};
+    use std::str::FromStr;
+    let file = std::env::temp_dir().join("ced_no_log_test.csv");
+    std::fs::write(&file, "id,name\n1,john\n").unwrap();
+    let import = Command::from_str(&format!("import {} true", file.display()))?;
+    let export = Command::from_str(&format!("export {}", file.display()))?;
+
+    let mut processor = Processor::new();
+    processor.add_page("log", "id", true, None, false, None)?;
+    processor.execute_command(&import)?;
+    assert!(!TEST_STDOUT.with(|stdout| stdout.take()).is_empty());
+
+    processor.set_print_logs(false);
+    processor.execute_command(&import)?;
+    processor.execute_command(&export)?;
+    std::fs::remove_file(&file).unwrap();
+    assert!(TEST_STDOUT.with(|stdout| stdout.take()).is_empty());
+    Ok(())
+}
+
+#[test]
+fn range_test() -> CedResult<()> {
+    use crate::{Processor, ValueRange};
+    use dcsv::{Value, ValueType};
+    assert!(ValueRange::min_max(Value::Number(3), Value::Number(1)).is_err());
+    let range = ValueRange::parse("[0, 150]", ValueType::Number)?;
+    assert!(range.contains(&Value::Number(150)));
+    assert!(!range.contains(&Value::Number(-1)));
+
+    let mut processor = Processor::new();
+    processor.add_page(
+        "range",
+        "age,born\n20,2001-02-03\n200,1999-12-31",
+        true,
+        None,
+        false,
+        None,
+    )?;
+    processor.set_schema_from_string(
+        "range",
+        "column,type,default,variant,pattern\nage,Number,0,\"[0,150]\",",
+        true,
+    )?;
+    let errors = processor.validate_page("range")?;
+    assert_eq!(errors.len(), 1);
+    assert_eq!((errors[0].row, errors[0].value.as_str()), (1, "200"));
+    assert!(!processor.set_cell_if_valid("range", 0, 0, "151")?);
+    assert!(processor
+        .export_schema("range")?
+        .contains("age,Number,0,\"[0,150]\","));
+
+    // Iso dates are compared as texts
+    let text = |s: &str| Value::Text(s.to_owned());
+    let dates = ValueRange::min_max(text("2000-01-01"), text("2030-12-31"))?;
+    processor.set_range("range", "born", Some(dates))?;
+    processor.rename_column("range", "born", "birth")?;
+    assert_eq!(processor.validate_page("range")?.len(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "presets")]
+fn date_preset_test() -> CedResult<()> {
+    use crate::Preset;
+    use dcsv::Value;
+    let preset = Preset::new(true)?;
+    let date = preset.get("date").unwrap();
+    assert!(date.qualify(&Value::Text("2022-02-28".to_owned())));
+    assert!(!date.qualify(&Value::Text("on 2022-02-28".to_owned())));
+    assert!(!date.qualify(&Value::Text("2022-02-280".to_owned())));
+    Ok(())
+}
+
+#[test]
+fn bool_column_test() -> CedResult<()> {
+    use crate::{Command, Processor};
+    use std::str::FromStr;
+    let mut processor = Processor::new();
+    processor.set_print_logs(false);
+    processor.add_page("bool", "id,flag\n1,true", true, None, false, None)?;
+    processor.set_schema_from_string(
+        "bool",
+        "column,type,default,variant,pattern\nflag,Text,false,true false,",
+        true,
+    )?;
+    processor.execute_command(&Command::from_str("add-row 1 2,YES")?)?;
+    processor.edit_cell("bool", 0, 1, "0")?;
+    assert!(processor.set_cell_if_valid("bool", 1, 1, "No")?);
+    assert!(!processor.set_cell_if_valid("bool", 1, 1, "maybe")?);
+    processor.add_row_from_string_array("bool", 2, &["3", "1"])?;
+    let flags = processor
+        .get_column_values("bool", "flag")?
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(flags, vec!["false", "false", "true"]);
+    Ok(())
+}
+
+#[test]
+fn diff_is_not_error_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    processor.set_print_logs(false);
+    processor.add_page("old", "id\n1", true, None, false, None)?;
+    processor.add_page("new", "id\n2", true, None, false, None)?;
+    processor.add_page("script", "id", true, None, false, None)?;
+    // Script continues after differences are found
+    processor.execute_script_from_string("diff old new ; add-row 0 1")?;
+    assert_eq!(processor.get_row_count("script")?, 1);
+    #[cfg(feature = "cli")]
+    assert!(processor.check_failed);
+    Ok(())
+}
+
+#[test]
+fn sort_decimal_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    processor.add_page(
+        "sort",
+        "price,id\n10.5,1\n-0.5,2\n,3\n9.25,4",
+        true,
+        None,
+        false,
+        None,
+    )?;
+    processor.sort_rows("sort", "price", true)?;
+    let prices = processor
+        .get_column_values("sort", "price")?
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(prices, vec!["", "-0.5", "9.25", "10.5"]);
+    Ok(())
+}
+
+#[test]
+fn apply_to_column_test() -> CedResult<()> {
+    use crate::Processor;
+    use dcsv::Value;
+    let mut processor = Processor::new();
+    processor.add_page("names", "id,name\n1,john\n2,jane", true, None, false, None)?;
+    processor.set_schema_from_string(
+        "names",
+        "column,type,default,variant,pattern\nid,Text,,,\nname,Text,john,john JOHN jane,",
+        true,
+    )?;
+    let (count, skipped) = processor.apply_to_column("names", "name", |value| {
+        Ok(Value::Text(value.to_string().to_uppercase()))
+    })?;
+    assert_eq!(count, 1);
+    assert_eq!(
+        skipped.iter().map(|(row, _)| *row).collect::<Vec<_>>(),
+        vec![1]
+    );
+    Ok(())
+}
+
+#[test]
+fn sample_rows_test() -> CedResult<()> {
+    use crate::Processor;
+    let mut processor = Processor::new();
+    let data = (0..100)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    processor.add_page("sample", &format!("id\n{}", data), true, None, false, None)?;
+    let rows = processor.sample_rows("sample", 10, Some(42))?;
+    assert_eq!(rows.len(), 10);
+    assert_eq!(rows, processor.sample_rows("sample", 10, Some(42))?);
+    assert_eq!(processor.sample_rows("sample", 200, Some(1))?.len(), 100);
     Ok(())
 }
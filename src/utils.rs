@@ -1,15 +1,27 @@
 use crate::error::{CedError, CedResult};
+use dcsv::{Column, Value, ValueType};
+use regex::Regex;
+use std::cmp::Ordering;
 use std::ffi::OsStr;
 use std::io::Write;
 use std::process::Stdio;
+use std::str::FromStr;
 
 pub(crate) const DEFAULT_DELIMITER: &str = ",";
 
+#[cfg(test)]
+thread_local! {
+    /// Stdout content of a test thread instead of a terminal
+    pub(crate) static TEST_STDOUT: std::cell::RefCell<String> = std::cell::RefCell::new(String::new());
+}
+
 #[allow(unused_variables)]
 pub fn write_to_stdout(src: &str) -> CedResult<()> {
     #[cfg(not(test))]
     write!(std::io::stdout(), "{}", src)
         .map_err(|err| CedError::io_error(err, "Failed to write to stdout"))?;
+    #[cfg(test)]
+    TEST_STDOUT.with(|stdout| stdout.borrow_mut().push_str(src));
     std::io::stdout()
         .flush()
         .map_err(|err| CedError::io_error(err, "Failed to flush stdout"))?;
@@ -113,9 +125,208 @@ pub(crate) fn subprocess(
     Ok(())
 }
 
+/// Parse an argument into a given type
+///
+/// Error message includes the name of the argument and the token that failed
+pub(crate) fn parse_arg<T>(arg: &str, name: &str) -> CedResult<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    arg.parse::<T>().map_err(|err| {
+        CedError::ParseError(format!("Invalid {} argument \"{}\" : {}", name, arg, err))
+    })
+}
+
+/// Get levenshtein distance between two strings
+pub(crate) fn levenshtein(source: &str, target: &str) -> usize {
+    let target = target.chars().collect::<Vec<_>>();
+    let mut previous = (0..=target.len()).collect::<Vec<_>>();
+    for (i, src_ch) in source.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, tar_ch) in target.iter().enumerate() {
+            let cost = if src_ch == *tar_ch { 0 } else { 1 };
+            let distance = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+            current.push(distance);
+        }
+        previous = current;
+    }
+    previous[target.len()]
+}
+
+/// Find the closest candidate to a source
+///
+/// This returns None if no candidate is close enough
+pub(crate) fn closest_match<'a>(source: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(source, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Build a regex from a given pattern
+pub(crate) fn build_regex(pattern: &str) -> CedResult<Regex> {
+    Regex::new(pattern).map_err(|err| {
+        CedError::CommandError(format!(
+            "\"{}\" is not a valid regex pattern =\n{}",
+            pattern, err
+        ))
+    })
+}
+
+/// Compare two values for sorting
+///
+/// Numbers are compared numerically and everything else lexicographically
+pub(crate) fn compare_values(lhs: &Value, rhs: &Value) -> Ordering {
+    match (lhs, rhs) {
+        (Value::Number(lhs), Value::Number(rhs)) => lhs.cmp(rhs),
+        _ => lhs.to_string().cmp(&rhs.to_string()),
+    }
+}
+
+/// Check if a column is a bool column
+///
+/// Bool column is a text column limited to variants of "true" and "false" such as a column with
+/// "bool" preset.
+pub(crate) fn is_bool_column(column: &Column) -> bool {
+    let mut variants = match column.limiter.get_variant() {
+        Some(variants) if column.column_type == ValueType::Text => {
+            variants.iter().map(|v| v.to_string()).collect::<Vec<_>>()
+        }
+        _ => return false,
+    };
+    variants.sort();
+    variants == ["false", "true"]
+}
+
+/// Get a normalized bool literal for a bool column
+///
+/// "true", "yes", "1" and "false", "no", "0" are accepted case-insensitively. This returns None
+/// if column is not a bool column or source is not a bool literal.
+pub(crate) fn bool_literal(column: &Column, src: &str) -> Option<&'static str> {
+    if !is_bool_column(column) {
+        return None;
+    }
+    match src.trim().to_lowercase().as_str() {
+        "true" | "yes" | "1" => Some("true"),
+        "false" | "no" | "0" => Some("false"),
+        _ => None,
+    }
+}
+
+/// Normalize a bool literal value for a bool column
+pub(crate) fn normalize_bool(column: &Column, value: Value) -> Value {
+    match &value {
+        Value::Text(text) => match bool_literal(column, text) {
+            Some(literal) => Value::Text(literal.to_owned()),
+            None => value,
+        },
+        Value::Number(_) => value,
+    }
+}
+
+/// Convert a string into a value of a column's type
+///
+/// Bool literal is normalized for a bool column
+pub(crate) fn column_value(column: &Column, src: &str) -> CedResult<Value> {
+    Ok(normalize_bool(
+        column,
+        Value::from_str(src, column.column_type)?,
+    ))
+}
+
+/// Parse a value as a finite decimal number
+pub(crate) fn parse_decimal(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(num) => Some(*num as f64),
+        Value::Text(text) => text.parse::<f64>().ok().filter(|num| num.is_finite()),
+    }
+}
+
+/// Compare two values as decimal numbers
+///
+/// Values which are not decimal numbers, such as empty values, are compared as
+/// ```compare_values```
+pub(crate) fn compare_decimals(lhs: &Value, rhs: &Value) -> Ordering {
+    match (parse_decimal(lhs), parse_decimal(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        _ => compare_values(lhs, rhs),
+    }
+}
+
+/// Escape a string as a quoted json string
+pub(crate) fn escape_json(source: &str) -> String {
+    let mut escaped = String::with_capacity(source.len() + 2);
+    escaped.push('"');
+    for ch in source.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Quote a csv field if it includes a delimiter, double quote or newline
+pub(crate) fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Parse a delimiter argument
+///
+/// "\\t" and "tab" are interpreted as a tab character
+pub(crate) fn parse_delimiter(source: &str) -> CedResult<char> {
+    if matches!(source.to_lowercase().as_str(), "\\t" | "tab") {
+        return Ok('\t');
+    }
+    let mut chars = source.chars();
+    match (chars.next(), chars.next()) {
+        (Some(delimiter), None) => Ok(delimiter),
+        _ => Err(CedError::CommandError(format!(
+            "\"{}\" is not a valid delimiter. Delimiter should be a single character",
+            source
+        ))),
+    }
+}
+
+/// Check if given name is a valid variable name
+///
+/// Variable name starts with an alphabet and consists of alphanumerics and underscores
+pub(crate) fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() => {
+            chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Get variable name from a token such as "$FILE"
+pub(crate) fn variable_reference(token: &str) -> Option<&str> {
+    token
+        .strip_prefix('$')
+        .filter(|name| is_variable_name(name))
+}
+
 /// Check if given string has valid csv spec
 ///
-/// This will return None if given value doesn't qualify with csv spec
+/// This will return None if given value doesn't qualify with csv spec. Line break is allowed
+/// inside double quotes as RFC 4180.
 pub(crate) fn is_valid_csv(value: &str) -> bool {
     let mut on_quote = false;
     let mut previous = ' ';
@@ -150,14 +361,50 @@ pub(crate) fn is_valid_csv(value: &str) -> bool {
     !on_quote
 }
 
+/// Split source into tokens by whitespace
+///
+/// Single quotes group a token and are removed. A backslash escapes a single quote. Csv style
+/// double quotes also group a token but are kept so that a value is parsed as csv later. Doubled
+/// double quote inside is an escaped literal as csv spec.
 pub fn tokens_with_quote(source: &str) -> Vec<String> {
+    tokenize(source, true)
+}
+
+/// Split source into tokens by whitespace with double quotes removed
+///
+/// This is same with ```tokens_with_quote``` but csv style double quotes are removed and doubled
+/// double quote inside becomes a literal double quote.
+pub fn tokens_without_double_quote(source: &str) -> Vec<String> {
+    tokenize(source, false)
+}
+
+fn tokenize(source: &str, keep_double_quote: bool) -> Vec<String> {
     let mut tokens = vec![];
     let mut on_quote = false;
+    let mut on_double_quote = false;
     let mut previous = ' ';
     let mut chunk = String::new();
-    let iter = source.chars().peekable();
-    for ch in iter {
+    let mut iter = source.chars().peekable();
+    while let Some(ch) = iter.next() {
         match ch {
+            // Doubled double quote is a literal when quotes are removed
+            '"' if !on_quote
+                && !keep_double_quote
+                && on_double_quote
+                && iter.peek() == Some(&'"') =>
+            {
+                iter.next();
+                previous = ch;
+            }
+            // Everything is literal inside double quotes except double quote itself
+            '"' if !on_quote => {
+                on_double_quote = !on_double_quote;
+                previous = ch;
+                if !keep_double_quote {
+                    continue;
+                }
+            }
+            _ if on_double_quote => previous = ch,
             // Escape character should not bed added
             '\\' => {
                 if previous == '\\' {
@@ -199,3 +446,77 @@ pub fn tokens_with_quote(source: &str) -> Vec<String> {
     }
     tokens
 }
+
+/// Strip a comment from a script line
+///
+/// A comment starts with "#" where a command starts, which is the start of a line or after a
+/// semicolon with optional whitespaces, and lasts until the end of the line. "#" inside an
+/// argument such as "#fff" is not a comment.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut on_quote = false;
+    let mut on_double_quote = false;
+    let mut command_start = true;
+    let mut previous = ' ';
+    for (idx, ch) in line.char_indices() {
+        match ch {
+            '#' if command_start => return &line[..idx],
+            ';' if !on_quote && !on_double_quote => command_start = true,
+            _ if ch.is_whitespace() => (),
+            '"' if !on_quote => {
+                on_double_quote = !on_double_quote;
+                command_start = false;
+            }
+            '\'' if !on_double_quote && previous != '\\' => {
+                on_quote = !on_quote;
+                command_start = false;
+            }
+            _ => command_start = false,
+        }
+        previous = ch;
+    }
+    line
+}
+
+/// Small pseudo random number generator ( splitmix64 )
+///
+/// This is not cryptographically secure but enough for sampling rows. Rand crate is not used
+/// because sampling is the only consumer and a whole dependency is not worth it. A same seed
+/// should always yield same rows, which is easier to keep with a generator that lives in ced.
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create a generator from a seed or from current time if seed is none
+    pub fn new(seed: Option<u64>) -> Self {
+        let state = seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Get a number in range of 0..bound uniformly
+    ///
+    /// Numbers below 2^64 mod bound are rejected so that modulo is not biased
+    pub fn below(&mut self, bound: usize) -> usize {
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let number = self.next_u64();
+            if number >= threshold {
+                return (number % bound) as usize;
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::utils;
+use crate::{CedError, CedResult};
+use dcsv::{Value, ValueType};
+use std::fmt::Display;
+
+/// A cell which violates its column's type or limiter
+#[derive(Debug, Clone)]
+pub struct ValidationError {
+    pub row: usize,
+    pub column: String,
+    pub value: String,
+    pub reason: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({},{}) \"{}\" : {}",
+            self.row, self.column, self.value, self.reason
+        )
+    }
+}
+
+/// An inclusive range of values which a column accepts
+///
+/// Range is checked along with a column's limiter by validation. Decimal numbers are compared
+/// numerically and other texts lexicographically, thus ISO dates ( YYYY-MM-DD ) can also be a
+/// range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueRange {
+    pub min: Value,
+    pub max: Value,
+}
+
+impl ValueRange {
+    /// Create a range from min and max
+    ///
+    /// Min and max should have a same type and min should not be greater than max.
+    pub fn min_max(min: Value, max: Value) -> CedResult<Self> {
+        if min.get_type() != max.get_type() {
+            return Err(CedError::InvalidRowData(format!(
+                "Range \"{}\" and \"{}\" are not a same type",
+                min, max
+            )));
+        }
+        if utils::compare_decimals(&min, &max).is_gt() {
+            return Err(CedError::InvalidRowData(format!(
+                "Range min \"{}\" is greater than max \"{}\"",
+                min, max
+            )));
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Parse a range notation such as "[0,150]" as a given type
+    pub fn parse(source: &str, value_type: ValueType) -> CedResult<Self> {
+        let (min, max) = source
+            .trim()
+            .strip_prefix('[')
+            .and_then(|range| range.strip_suffix(']'))
+            .and_then(|range| range.split_once(','))
+            .ok_or_else(|| {
+                CedError::ParseError(format!(
+                    "\"{}\" is not a range. Range should be \"[min,max]\"",
+                    source
+                ))
+            })?;
+        Self::min_max(
+            Value::from_str(min.trim(), value_type)?,
+            Value::from_str(max.trim(), value_type)?,
+        )
+    }
+
+    /// Get a type of range
+    pub fn get_type(&self) -> ValueType {
+        self.min.get_type()
+    }
+
+    /// Check if value is inside of range
+    pub fn contains(&self, value: &Value) -> bool {
+        value.get_type() == self.get_type()
+            && utils::compare_decimals(&self.min, value).is_le()
+            && utils::compare_decimals(value, &self.max).is_le()
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.min, self.max)
+    }
+}